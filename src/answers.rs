@@ -0,0 +1,48 @@
+use crate::parse::parse_entries;
+use crate::{ParseError, Part};
+
+/// Default location of the expected answers, relative to the repository root.
+pub static ANSWERS_PATH: &str = "answers.txt";
+
+/// The known answer of one part of a day for a given input file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Parses an answers file: one `<day> <part> <input file> <answer>` entry per
+/// line, lines starting with `#` being comments.
+pub fn parse(input: &str) -> Result<Vec<Expected>, ParseError> {
+    parse_entries(input, "'<day> <part> <input file> <answer>'", |entry| {
+        Ok(Expected {
+            day: entry.day,
+            part: entry.part,
+            input: entry.fields[0].to_string(),
+            answer: entry.fields[1].to_string(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let entries = parse("# comment\n7 2 input/input07.txt 6683\n").unwrap();
+        assert_eq!(
+            entries,
+            vec![Expected {
+                day: 7,
+                part: Part::Two,
+                input: "input/input07.txt".to_string(),
+                answer: "6683".to_string(),
+            }]
+        );
+        let err = parse("7 3 input/input07.txt 6683\n").unwrap_err();
+        assert_eq!(err, ParseError::new(3, "part 1 or 2", "3").at_line(1));
+    }
+}
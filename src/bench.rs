@@ -0,0 +1,169 @@
+use crate::registry::Runner;
+use crate::{ParseError, Part};
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of the samples timed for one phase, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        Stats {
+            min_ns: ns(samples[0]),
+            median_ns: ns(samples[samples.len() / 2]),
+            max_ns: ns(samples[samples.len() - 1]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Stats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Stats>,
+}
+
+impl DayReport {
+    /// The timed phases, in the order they run.
+    pub fn phases(&self) -> Vec<(&'static str, Stats)> {
+        let mut ret = vec![("parse", self.parse)];
+        ret.extend(self.part1.map(|stats| ("part1", stats)));
+        ret.extend(self.part2.map(|stats| ("part2", stats)));
+        ret
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+fn time<T, F: FnMut() -> T>(iterations: usize, mut f: F) -> Stats {
+    let mut samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::new(&mut samples)
+}
+
+/// Times parsing `input` and solving `parts`, each over `iterations` runs.
+pub fn bench_day(
+    runner: &dyn Runner,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<DayReport, ParseError> {
+    let parsed = runner.parse(input)?;
+    let parse = time(iterations, || runner.parse(input));
+    let solve = |part: Part| {
+        if parts.contains(&part) {
+            Some(time(iterations, || parsed.solve(part)))
+        } else {
+            None
+        }
+    };
+    Ok(DayReport {
+        day: runner.day(),
+        parse,
+        part1: solve(Part::One),
+        part2: solve(Part::Two),
+    })
+}
+
+/// Median time of one phase in a baseline run and in the current one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub day: u8,
+    pub phase: &'static str,
+    pub before_ns: u64,
+    pub after_ns: u64,
+}
+
+impl Change {
+    /// Relative change of the median, in percent.
+    pub fn percent(&self) -> f64 {
+        (self.after_ns as f64 - self.before_ns as f64) * 100.0 / self.before_ns.max(1) as f64
+    }
+}
+
+/// Pairs every phase of `current` with the same phase in `baseline`.
+pub fn compare(baseline: &Report, current: &Report) -> Vec<Change> {
+    let mut ret = Vec::new();
+    for day in &current.days {
+        let before = match baseline.days.iter().find(|d| d.day == day.day) {
+            Some(before) => before.phases(),
+            None => continue,
+        };
+        for (phase, stats) in day.phases() {
+            if let Some((_, before)) = before.iter().find(|(p, _)| *p == phase) {
+                ret.push(Change {
+                    day: day.day,
+                    phase,
+                    before_ns: before.median_ns,
+                    after_ns: stats.median_ns,
+                });
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [5, 1, 3, 2, 4]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let stats = Stats::new(&mut samples);
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let stats = |median_ns| Stats {
+            min_ns: 0,
+            median_ns,
+            max_ns: 0,
+        };
+        let report = |median_ns| Report {
+            iterations: 1,
+            days: vec![DayReport {
+                day: 1,
+                parse: stats(median_ns),
+                part1: Some(stats(median_ns)),
+                part2: None,
+            }],
+        };
+        let changes = compare(&report(100), &report(150));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].phase, "part1");
+        assert!((changes[1].percent() - 50.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// Command line options of a subcommand: `--name value` pairs and bare
+/// `--flag` switches.
+#[derive(Debug, Default)]
+pub struct Args {
+    values: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    /// Parses `args`, where `flags` lists the options that take no value and
+    /// `options` those taking one. Any other option is an error.
+    pub fn parse<I: IntoIterator<Item = String>>(
+        args: I,
+        flags: &[&str],
+        options: &[&str],
+    ) -> Result<Self, String> {
+        let mut ret = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let name = match arg.strip_prefix("--") {
+                Some(name) => name.to_string(),
+                None => return Err(format!("Unexpected argument '{}'", arg)),
+            };
+            if flags.contains(&name.as_str()) {
+                ret.flags.insert(name);
+            } else if !options.contains(&name.as_str()) {
+                return Err(format!("Unknown option --{}", name));
+            } else {
+                match args.next() {
+                    Some(value) => {
+                        ret.values.insert(name, value);
+                    }
+                    None => return Err(format!("Missing value for --{}", name)),
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.value(name)
+            .map(|value| {
+                value
+                    .parse::<T>()
+                    .map_err(|_| format!("Invalid value '{}' for --{}", value, name))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(
+            args.iter().map(|arg| arg.to_string()),
+            &["all"],
+            &["day", "part"],
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse(&["--day", "7", "--all"]).unwrap();
+        assert_eq!(args.value("day"), Some("7"));
+        assert_eq!(args.parse_value::<u8>("part"), Ok(None));
+        assert!(args.flag("all"));
+        assert_eq!(
+            parse(&["--day", "7", "--prat", "2"]).unwrap_err(),
+            "Unknown option --prat"
+        );
+        assert_eq!(parse(&["--day"]).unwrap_err(), "Missing value for --day");
+        assert!(parse(&["7"]).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::args::Args;
+use aoc::days::d02::{self, AuditLine};
+use aoc::input::Source;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Lists every password of a day 2 input with the verdict of each policy and
+/// its reason, then the lines that could not be parsed. Fails if there are
+/// some.
+pub fn audit(args: &Args) -> Result<bool, String> {
+    let policies = match args.value("policies") {
+        Some(filename) => {
+            let json = fs::read_to_string(filename)
+                .map_err(|err| format!("Could not read {}: {}", filename, err))?;
+            d02::parse_policies(&json)?
+        }
+        None => d02::puzzle_policies()?,
+    };
+    let input = args
+        .value("input")
+        .map_or_else(|| aoc::input_path(2), str::to_string);
+    let content = Source::from_arg(&input)
+        .read()
+        .map_err(|err| err.to_string())?;
+
+    let audited = d02::audit(&content, &policies);
+    let mut valid: BTreeMap<&str, usize> = policies.keys().map(|name| (name.as_str(), 0)).collect();
+    let mut rejected = Vec::new();
+    for line in &audited {
+        match line {
+            AuditLine::Checked {
+                line,
+                text,
+                verdicts,
+            } => {
+                println!("line {}: {}", line, text);
+                for (name, verdict) in verdicts {
+                    let status = if verdict.valid { "valid" } else { "invalid" };
+                    println!("    {}: {}, {}", name, status, verdict.reason);
+                    if verdict.valid {
+                        *valid.get_mut(name.as_str()).unwrap() += 1;
+                    }
+                }
+            }
+            AuditLine::Rejected(err) => rejected.push(err),
+        }
+    }
+    for err in &rejected {
+        println!(
+            "line {}: rejected, expected {} at column {}, found {:?}",
+            err.line, err.expected, err.column, err.found
+        );
+    }
+
+    println!(
+        "{} lines checked, {} rejected",
+        audited.len() - rejected.len(),
+        rejected.len()
+    );
+    for (name, count) in valid {
+        println!("{}: {} valid", name, count);
+    }
+    Ok(rejected.is_empty())
+}
@@ -0,0 +1,99 @@
+use crate::args::Args;
+use crate::select;
+use aoc::bench::{self, Report};
+use log::error;
+use std::fs;
+use std::time::Duration;
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+fn load_report(filename: &str) -> Result<Report, String> {
+    let content = fs::read_to_string(filename)
+        .map_err(|err| format!("Could not load {}: {}", filename, err))?;
+    serde_json::from_str(&content).map_err(|err| format!("Invalid report {}: {}", filename, err))
+}
+
+/// Times the selected days, then optionally saves the report and compares it
+/// with a baseline. Fails when a phase got slower than the threshold.
+pub fn bench(args: &Args) -> Result<bool, String> {
+    let selection = select(args)?;
+    // At least one run is timed, and the report says so.
+    let iterations = args
+        .parse_value("iterations")?
+        .unwrap_or(DEFAULT_ITERATIONS)
+        .max(1);
+    let threshold = args.parse_value("threshold")?.unwrap_or(DEFAULT_THRESHOLD);
+    let baseline = args.value("baseline").map(load_report).transpose()?;
+
+    let mut success = true;
+    let mut report = Report {
+        iterations,
+        days: Vec::new(),
+    };
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    for &runner in &selection.runners {
+        let source = selection.source(runner);
+        let day = source
+            .read()
+            .map_err(|err| err.to_string())
+            .and_then(|content| {
+                bench::bench_day(runner, &content, &selection.parts, iterations)
+                    .map_err(|err| err.in_file(&source.to_string()).to_string())
+            });
+        let day = match day {
+            Ok(day) => day,
+            Err(err) => {
+                error!("Day {}: {}", runner.day(), err);
+                success = false;
+                continue;
+            }
+        };
+        for (phase, stats) in day.phases() {
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                day.day,
+                phase,
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.max_ns)
+            );
+        }
+        report.days.push(day);
+    }
+
+    if let Some(filename) = args.value("save") {
+        let json = serde_json::to_string_pretty(&report).map_err(|err| err.to_string())?;
+        fs::write(filename, json)
+            .map_err(|err| format!("Could not write {}: {}", filename, err))?;
+    }
+
+    if let Some(baseline) = baseline {
+        println!();
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}",
+            "Day", "Phase", "Before", "After", "Change"
+        );
+        for change in bench::compare(&baseline, &report) {
+            let regressed = change.percent() > threshold;
+            success &= !regressed;
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>+7.1}%{}",
+                change.day,
+                change.phase,
+                format_ns(change.before_ns),
+                format_ns(change.after_ns),
+                change.percent(),
+                if regressed { "  REGRESSION" } else { "" }
+            );
+        }
+    }
+    Ok(success)
+}
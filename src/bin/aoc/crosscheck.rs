@@ -0,0 +1,54 @@
+use crate::args::Args;
+use crate::output::describe;
+use aoc::generate;
+use aoc::registry::{self, Runner};
+
+const DEFAULT_SEEDS: u64 = 20;
+const DEFAULT_SIZE: usize = 500;
+
+/// Runs every variant of the days having both variants and a generator on
+/// generated inputs, reporting each answer differing from the main one.
+pub fn crosscheck(args: &Args) -> Result<bool, String> {
+    let seeds = args.parse_value("seeds")?.unwrap_or(DEFAULT_SEEDS);
+    let size = args.parse_value("size")?.unwrap_or(DEFAULT_SIZE);
+    let runners: Vec<&dyn Runner> = match args.parse_value::<u8>("day")? {
+        Some(day) => {
+            vec![registry::get(day).ok_or(format!("No solver registered for day {}", day))?]
+        }
+        None => registry::all().to_vec(),
+    };
+
+    let mut failed = 0;
+    for runner in runners {
+        let day = runner.day();
+        let variants =
+            runner.variants(aoc::Part::One).len() + runner.variants(aoc::Part::Two).len();
+        if variants == 0 || !generate::days().any(|d| d == day) {
+            continue;
+        }
+        let mut day_failed = 0;
+        for seed in 0..seeds {
+            let generated = generate::generate(day, seed, size).unwrap();
+            let parsed = runner
+                .parse(&generated.input)
+                .map_err(|err| format!("Day {} seed {}: {}", day, seed, err))?;
+            for disagreement in registry::crosscheck(runner, parsed.as_ref()) {
+                println!(
+                    "FAIL  day {:>2} part {}  seed {}  variant {}",
+                    day, disagreement.part, seed, disagreement.variant
+                );
+                println!("    expected: {}", describe(&disagreement.expected));
+                println!("    actual:   {}", describe(&disagreement.found));
+                day_failed += 1;
+            }
+        }
+        if day_failed == 0 {
+            println!(
+                "PASS  day {:>2}  {} variants on {} inputs",
+                day, variants, seeds
+            );
+        }
+        failed += day_failed;
+    }
+    Ok(failed == 0)
+}
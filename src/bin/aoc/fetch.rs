@@ -0,0 +1,33 @@
+use crate::args::Args;
+use aoc::client::{self, Client, Fetched};
+use std::env;
+use std::path::Path;
+
+/// Environment variable holding the session cookie when `--session` is not
+/// given.
+pub static SESSION_VAR: &str = "AOC_SESSION";
+
+/// Builds a client from `--base-url` and `--session`, or `$AOC_SESSION`.
+pub fn client(args: &Args) -> Result<Client, String> {
+    let session = match args.value("session") {
+        Some(session) => session.to_string(),
+        None => env::var(SESSION_VAR)
+            .map_err(|_| format!("Either --session or ${} is required", SESSION_VAR))?,
+    };
+    let base_url = args.value("base-url").unwrap_or(client::DEFAULT_BASE_URL);
+    Ok(Client::new(base_url, &session))
+}
+
+pub fn fetch(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    let client = client(args)?;
+    let path = aoc::input_path(day);
+    match client
+        .fetch_cached(day, Path::new(&path))
+        .map_err(|err| err.to_string())?
+    {
+        Fetched::Cached => println!("{} already downloaded", path),
+        Fetched::Downloaded => println!("Downloaded {}", path),
+    }
+    Ok(true)
+}
@@ -0,0 +1,33 @@
+use crate::args::Args;
+use aoc::generate;
+use std::fs;
+
+const DEFAULT_SIZE: usize = 1000;
+
+/// Writes a random input for a day. With `--output`, the known answers are
+/// printed as answers file entries, ready for `aoc verify --answers`;
+/// otherwise the input goes to stdout and the answers to stderr.
+pub fn generate(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    let seed = args.parse_value("seed")?.unwrap_or(0);
+    let size = args.parse_value("size")?.unwrap_or(DEFAULT_SIZE);
+    let generated = generate::generate(day, seed, size).ok_or(format!(
+        "No generator for day {}, only for days {:?}",
+        day,
+        generate::days().collect::<Vec<_>>()
+    ))?;
+    match args.value("output") {
+        Some(filename) => {
+            fs::write(filename, &generated.input)
+                .map_err(|err| format!("Could not write {}: {}", filename, err))?;
+            println!("{} 1 {} {}", day, filename, generated.part1);
+            println!("{} 2 {} {}", day, filename, generated.part2);
+        }
+        None => {
+            print!("{}", generated.input);
+            eprintln!("part 1: {}", generated.part1);
+            eprintln!("part 2: {}", generated.part2);
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,91 @@
+use log::{LevelFilter, Log, Metadata, Record};
+use std::io::{self, Write};
+
+/// Writes log records to stderr, keeping stdout for answers.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let _ = writeln!(
+                io::stderr().lock(),
+                "[{} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Removes the verbosity flags from `args`, wherever they are, and returns the
+/// level they select: `-q` only shows errors, warnings show by default, and
+/// each `-v` shows one more level, up to `-vvv` for every `d08` step.
+pub fn verbosity(args: &mut Vec<String>) -> Result<LevelFilter, String> {
+    let mut verbose = 0;
+    let mut quiet = false;
+    args.retain(|arg| match arg.as_str() {
+        "-q" | "--quiet" => {
+            quiet = true;
+            false
+        }
+        "--verbose" => {
+            verbose += 1;
+            false
+        }
+        _ => match arg.strip_prefix('-') {
+            Some(vs) if !vs.is_empty() && vs.chars().all(|c| c == 'v') => {
+                verbose += vs.len();
+                false
+            }
+            _ => true,
+        },
+    });
+    match (quiet, verbose) {
+        (true, 0) => Ok(LevelFilter::Error),
+        (true, _) => Err("-q cannot be combined with -v".to_string()),
+        (false, 0) => Ok(LevelFilter::Warn),
+        (false, 1) => Ok(LevelFilter::Info),
+        (false, 2) => Ok(LevelFilter::Debug),
+        (false, _) => Ok(LevelFilter::Trace),
+    }
+}
+
+pub fn init(level: LevelFilter) {
+    static LOGGER: StderrLogger = StderrLogger;
+    // Only fails if a logger is already set, which would then be used instead.
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_verbosity() {
+        let mut run = args(&["run", "-vv", "--day", "8", "-v"]);
+        assert_eq!(verbosity(&mut run), Ok(LevelFilter::Trace));
+        assert_eq!(run, args(&["run", "--day", "8"]));
+
+        let mut run = args(&["-q", "run", "--day", "-3"]);
+        assert_eq!(verbosity(&mut run), Ok(LevelFilter::Error));
+        assert_eq!(run, args(&["run", "--day", "-3"]));
+
+        assert_eq!(verbosity(&mut args(&["run"])), Ok(LevelFilter::Warn));
+        assert!(verbosity(&mut args(&["-q", "-v"])).is_err());
+        let mut stdin = args(&["run", "--input", "-"]);
+        assert_eq!(verbosity(&mut stdin), Ok(LevelFilter::Warn));
+        assert_eq!(stdin, args(&["run", "--input", "-"]));
+    }
+}
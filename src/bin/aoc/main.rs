@@ -0,0 +1,293 @@
+mod args;
+mod audit;
+mod bench;
+mod crosscheck;
+mod fetch;
+mod generate;
+mod logger;
+mod output;
+mod pool;
+mod query;
+mod repl;
+mod scaffold;
+mod submit;
+mod verify;
+mod watch;
+
+use aoc::input::Source;
+use aoc::registry::{self, Runner};
+use aoc::stream;
+use aoc::Part;
+use args::Args;
+use log::{error, info};
+use output::{Format, Record};
+use std::process;
+use std::time::Instant;
+
+static USAGE: &str = "Usage:
+    aoc run --day <day> [--part <part>] [--input <input file|->] [--stream]
+            [--format <format>]
+    aoc run --all [--jobs <threads>] [--format <format>]
+    aoc repl --day <day> [--input <input file>]
+    aoc query --day <day> --query '<query> [<args>]' [--input <input file|->]
+    aoc watch --day <day> [--input <input file>] [--interval <milliseconds>]
+    aoc verify [--answers <answers file>]
+    aoc audit [--input <input file|->] [--policies <policy file>]
+    aoc generate --day <day> [--size <lines>] [--seed <n>] [--output <input file>]
+    aoc crosscheck [--day <day>] [--seeds <n>] [--size <lines>]
+    aoc new --day <day> [--root <repository>]
+    aoc serve [--addr <host:port>] [--timeout <seconds>] [--workers <n>]
+              [--max-body <bytes>]
+    aoc fetch --day <day> [--session <token>] [--base-url <url>]
+    aoc submit --day <day> --part <part> [--input <input file|->]
+               [--guesses <guesses file>] [--session <token>] [--base-url <url>]
+    aoc bench (--day <day> [--part <part>] [--input <input file|->] | --all)
+              [--iterations <n>] [--save <report.json>]
+              [--baseline <report.json>] [--threshold <percent>]
+
+Formats: text (default), json, csv. Durations are in seconds.
+--stream solves while reading the input, for days 1, 4, 6, 9 and 10.
+Day 2 reads its password policies from policies/d02.json when it exists, and
+aoc audit explains them line by line.
+The session token defaults to $AOC_SESSION.
+aoc serve needs the server feature: cargo build --features server.
+Any command takes -v, -vv or -vvv for more diagnostics on stderr, -q for errors
+only.";
+
+struct Selection {
+    runners: Vec<&'static dyn Runner>,
+    parts: Vec<Part>,
+    input: Option<String>,
+}
+
+fn select(args: &Args) -> Result<Selection, String> {
+    let part = match args.parse_value::<u8>("part")? {
+        Some(part) => vec![Part::new(part).ok_or(format!("Unknown part {}", part))?],
+        None => Part::ALL.to_vec(),
+    };
+    if args.flag("all") {
+        if args.value("day").is_some() || args.value("input").is_some() {
+            return Err("--all cannot be combined with --day or --input".to_string());
+        }
+        return Ok(Selection {
+            runners: registry::all().to_vec(),
+            parts: part,
+            input: None,
+        });
+    }
+    let day = args
+        .parse_value::<u8>("day")?
+        .ok_or("Either --day or --all is required")?;
+    let runner = registry::get(day).ok_or(format!("No solver registered for day {}", day))?;
+    Ok(Selection {
+        runners: vec![runner],
+        parts: part,
+        input: args.value("input").map(str::to_string),
+    })
+}
+
+impl Selection {
+    /// The input given on the command line, or the day's conventional one.
+    fn source(&self, runner: &dyn Runner) -> Source<'static> {
+        match &self.input {
+            Some(input) => Source::from_arg(input),
+            None => Source::from_arg(&aoc::input_path(runner.day())),
+        }
+    }
+}
+
+/// Parses the input of one day and solves the selected parts.
+fn run_day(selection: &Selection, runner: &dyn Runner) -> Result<Vec<Record>, String> {
+    let start = Instant::now();
+    let parsed = selection
+        .source(runner)
+        .load_with(runner)
+        .map_err(|err| err.to_string())?;
+    info!("Day {}: parsed in {:?}", runner.day(), start.elapsed());
+    Ok(selection
+        .parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = parsed.solve(*part);
+            Record {
+                day: runner.day(),
+                part: part.number(),
+                answer,
+                duration: start.elapsed().as_secs_f64(),
+            }
+        })
+        .collect())
+}
+
+/// Like `run_day`, but solving while the input is read, for inputs too large
+/// to load whole. Both parts share the time spent reading.
+fn run_streaming(selection: &Selection, runner: &dyn Runner) -> Result<Vec<Record>, String> {
+    let source = selection.source(runner);
+    let start = Instant::now();
+    let answers = stream::solve(runner.day(), &source)
+        .ok_or(format!(
+            "cannot be streamed, only days {:?} can",
+            stream::DAYS
+        ))?
+        .map_err(|err| err.to_string())?;
+    let duration = start.elapsed().as_secs_f64();
+    Ok(selection
+        .parts
+        .iter()
+        .map(|part| Record {
+            day: runner.day(),
+            part: part.number(),
+            answer: answers[usize::from(part.number()) - 1].clone(),
+            duration,
+        })
+        .collect())
+}
+
+fn run(args: &Args) -> Result<bool, String> {
+    let selection = select(args)?;
+    let format = args.parse_value("format")?.unwrap_or(Format::Text);
+    let jobs = args.parse_value("jobs")?.unwrap_or(1);
+    if jobs == 0 {
+        return Err("--jobs must be at least 1".to_string());
+    }
+    let streaming = args.flag("stream");
+    let results = pool::map(&selection.runners, jobs, |&runner| {
+        if streaming {
+            run_streaming(&selection, runner)
+        } else {
+            run_day(&selection, runner)
+        }
+    });
+    let mut success = true;
+    let mut records = Vec::new();
+    for (runner, result) in selection.runners.iter().zip(results) {
+        match result {
+            Ok(Ok(day)) => {
+                success &= day.iter().all(|record| record.answer.is_some());
+                records.extend(day);
+            }
+            Ok(Err(err)) => {
+                error!("Day {}: {}", runner.day(), err);
+                success = false;
+            }
+            Err(panic) => {
+                error!("Day {}: panicked: {}", runner.day(), panic);
+                success = false;
+            }
+        }
+    }
+    output::print(format, &records);
+    Ok(success)
+}
+
+#[cfg(feature = "server")]
+fn serve(args: &Args) -> Result<bool, String> {
+    let addr = args.value("addr").unwrap_or("127.0.0.1:8080");
+    let mut limits = aoc::server::Limits::default();
+    if let Some(seconds) = args.parse_value::<u64>("timeout")? {
+        limits.timeout = std::time::Duration::from_secs(seconds);
+    }
+    if let Some(bytes) = args.parse_value("max-body")? {
+        limits.max_body = bytes;
+    }
+    if let Some(workers) = args.parse_value::<usize>("workers")? {
+        limits.workers = workers.max(1);
+    }
+    println!("Listening on http://{}", addr);
+    aoc::server::serve(addr, limits).map(|()| true)
+}
+
+#[cfg(not(feature = "server"))]
+fn serve(_: &Args) -> Result<bool, String> {
+    Err("aoc was built without the server feature, rebuild with --features server".to_string())
+}
+
+type Command = fn(&Args) -> Result<bool, String>;
+
+/// Each subcommand with its options taking no value, those taking one, and
+/// its entry point.
+static COMMANDS: &[(&str, &[&str], &[&str], Command)] = &[
+    (
+        "run",
+        &["all", "stream"],
+        &["day", "part", "input", "format", "jobs"],
+        run,
+    ),
+    ("audit", &[], &["input", "policies"], audit::audit),
+    (
+        "bench",
+        &["all"],
+        &[
+            "day",
+            "part",
+            "input",
+            "iterations",
+            "save",
+            "baseline",
+            "threshold",
+        ],
+        bench::bench,
+    ),
+    (
+        "crosscheck",
+        &[],
+        &["day", "seeds", "size"],
+        crosscheck::crosscheck,
+    ),
+    ("fetch", &[], &["day", "session", "base-url"], fetch::fetch),
+    (
+        "submit",
+        &[],
+        &["day", "part", "input", "guesses", "session", "base-url"],
+        submit::submit,
+    ),
+    (
+        "serve",
+        &[],
+        &["addr", "timeout", "workers", "max-body"],
+        serve,
+    ),
+    (
+        "generate",
+        &[],
+        &["day", "size", "seed", "output"],
+        generate::generate,
+    ),
+    ("new", &[], &["day", "root"], scaffold::new_day),
+    ("query", &[], &["day", "query", "input"], query::query),
+    ("repl", &[], &["day", "input"], repl::repl),
+    ("watch", &[], &["day", "input", "interval"], watch::watch),
+    ("verify", &[], &["answers"], verify::verify),
+];
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    match logger::verbosity(&mut args) {
+        Ok(level) => logger::init(level),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2)
+        }
+    }
+    let mut args = args.into_iter();
+    let command = args.next();
+    let result = match COMMANDS
+        .iter()
+        .find(|(name, ..)| Some(*name) == command.as_deref())
+    {
+        Some((_, flags, options, command)) => match Args::parse(args, flags, options) {
+            Ok(args) => command(&args),
+            Err(err) => Err(format!("{}\n\n{}", err, USAGE)),
+        },
+        None => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2)
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use aoc::Answer;
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The outcome of solving one part, `answer` being `None` when the solver
+/// found no solution.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub duration: f64,
+}
+
+/// An answer as printed for people, saying so when there is none.
+pub fn describe(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => "no solution found".to_string(),
+    }
+}
+
+fn csv_field(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Text(text)) => format!("\"{}\"", text.replace('"', "\"\"")),
+        Some(answer) => answer.to_string(),
+        None => String::new(),
+    }
+}
+
+pub fn print(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {
+            println!("{:>3}  {:>4}  {:>10}  Answer", "Day", "Part", "Time");
+            for record in records {
+                println!(
+                    "{:>3}  {:>4}  {:>10}  {}",
+                    record.day,
+                    record.part,
+                    format!("{:.1?}", Duration::from_secs_f64(record.duration)),
+                    describe(&record.answer)
+                );
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).unwrap()),
+        Format::Csv => {
+            println!("day,part,answer,duration");
+            for record in records {
+                println!(
+                    "{},{},{},{}",
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer),
+                    record.duration
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use aoc::panic_message;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Applies `f` to every item on `jobs` threads, returning the results in the
+/// order of `items`. A panicking call yields its panic message instead of
+/// aborting the other items.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<Result<R, String>>>> =
+        items.iter().map(|_| Mutex::new(None)).collect();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let item = match items.get(idx) {
+                    Some(item) => item,
+                    None => break,
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                *results[idx].lock().unwrap() = Some(result);
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u32> = (0..20).collect();
+        let results = map(&items, 4, |&item| {
+            if item == 7 {
+                panic!("no sevens");
+            }
+            item * 2
+        });
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[3], Ok(6));
+        assert_eq!(results[7], Err("no sevens".to_string()));
+        assert_eq!(results[19], Ok(38));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::args::Args;
+use aoc::input::Source;
+use aoc::registry;
+use log::error;
+
+/// Answers one of a day's queries, as the REPL would, for use from scripts.
+/// A query that fails, such as a search finding nothing, exits with 1.
+pub fn query(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    let runner = registry::get(day).ok_or(format!("No solver registered for day {}", day))?;
+    let query = args.value("query").ok_or("--query is required")?.trim();
+    let (name, arg) = match query.find(' ') {
+        Some(idx) => (&query[..idx], query[idx + 1..].trim()),
+        None => (query, ""),
+    };
+    if !runner.queries().iter().any(|known| known.name == name) {
+        let known: Vec<&str> = runner.queries().iter().map(|known| known.name).collect();
+        return Err(format!(
+            "Day {} has no query '{}', only {:?}",
+            day, name, known
+        ));
+    }
+    let input = args
+        .value("input")
+        .map_or_else(|| aoc::input_path(day), str::to_string);
+    let parsed = Source::from_arg(&input)
+        .load_with(runner)
+        .map_err(|err| err.to_string())?;
+    match parsed.query(name, arg) {
+        Some(Ok(result)) => {
+            println!("{}", result);
+            Ok(true)
+        }
+        Some(Err(err)) => {
+            error!("{}", err);
+            Ok(false)
+        }
+        None => Err(format!("Day {} has no query '{}'", day, name)),
+    }
+}
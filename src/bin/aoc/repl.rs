@@ -0,0 +1,99 @@
+use crate::args::Args;
+use crate::output::describe;
+use aoc::input::Source;
+use aoc::registry::{self, Parsed, Runner};
+use aoc::Part;
+use std::io::{self, BufRead, Write};
+use std::time::Instant;
+
+static COMMANDS: &[(&str, &str)] = &[
+    ("part <1|2>", "solves a part"),
+    ("parts", "solves both parts"),
+    ("dump", "prints the parsed input"),
+    ("reload", "reads and parses the input again"),
+    ("help", "lists the commands"),
+    ("quit", "leaves the REPL"),
+];
+
+fn help(runner: &dyn Runner) {
+    for (usage, help) in COMMANDS {
+        println!("    {:<24}  {}", usage, help);
+    }
+    for query in runner.queries() {
+        let usage = format!("{} {}", query.name, query.args);
+        println!("    {:<24}  {}", usage.trim_end(), query.help);
+    }
+}
+
+fn solve(parsed: &dyn Parsed, part: Part) {
+    let start = Instant::now();
+    let answer = parsed.solve(part);
+    let duration = start.elapsed();
+    println!("part {}: {}  ({:.1?})", part, describe(&answer), duration);
+}
+
+/// Evaluates one command line, returning `false` when the session is over.
+fn eval(runner: &dyn Runner, source: &Source, parsed: &mut Box<dyn Parsed>, line: &str) -> bool {
+    let line = line.trim();
+    let (name, arg) = match line.find(' ') {
+        Some(idx) => (&line[..idx], line[idx + 1..].trim()),
+        None => (line, ""),
+    };
+    match name {
+        "" => {}
+        "quit" | "exit" => return false,
+        "help" => help(runner),
+        "dump" => println!("{}", parsed.dump()),
+        "parts" => Part::ALL
+            .iter()
+            .for_each(|&part| solve(parsed.as_ref(), part)),
+        "part" => match arg.parse().ok().and_then(Part::new) {
+            Some(part) => solve(parsed.as_ref(), part),
+            None => println!("Unknown part '{}'", arg),
+        },
+        "reload" => match source.load_with(runner) {
+            Ok(reloaded) => {
+                *parsed = reloaded;
+                println!("Reloaded {}", source);
+            }
+            Err(err) => println!("{}", err),
+        },
+        _ => match parsed.query(name, arg) {
+            Some(Ok(result)) => println!("{}", result),
+            Some(Err(err)) => println!("{}", err),
+            None => println!("Unknown command '{}', try help", name),
+        },
+    }
+    true
+}
+
+/// Loads a day's input once and answers commands read from stdin.
+pub fn repl(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    let runner = registry::get(day).ok_or(format!("No solver registered for day {}", day))?;
+    let input = args
+        .value("input")
+        .map_or_else(|| aoc::input_path(day), str::to_string);
+    if input == "-" {
+        return Err("The REPL reads its commands from stdin, --input cannot be -".to_string());
+    }
+    let source = Source::from_arg(&input);
+    let mut parsed = source.load_with(runner).map_err(|err| err.to_string())?;
+    println!("Loaded {}, type help for the commands", source);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("day{:02}> ", day);
+        io::stdout().flush().map_err(|err| err.to_string())?;
+        let line = match lines.next() {
+            Some(line) => line.map_err(|err| err.to_string())?,
+            None => break,
+        };
+        if !eval(runner, &source, &mut parsed, &line) {
+            break;
+        }
+    }
+    println!();
+    Ok(true)
+}
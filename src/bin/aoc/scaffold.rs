@@ -0,0 +1,149 @@
+use crate::args::Args;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static TEMPLATE: &str = include_str!("../../../templates/day.rs.tmpl");
+
+/// Inserts `line` among the lines of `content` starting with `prefix`,
+/// keeping them sorted. Returns `None` if no line starts with `prefix`.
+fn insert_sorted(content: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+    let idx = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix) && *l < line)
+        .map_or(first, |idx| idx + 1);
+    lines.insert(idx, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// The content of `path` with `line` registered among the lines starting
+/// with `prefix`, failing if it is already there.
+fn registered(path: &Path, prefix: &str, line: &str) -> Result<String, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("Could not load {}: {}", path.display(), err))?;
+    if content.lines().any(|l| l == line) {
+        return Err(format!(
+            "{} already contains '{}'",
+            path.display(),
+            line.trim()
+        ));
+    }
+    insert_sorted(&content, prefix, line)
+        .ok_or_else(|| format!("Could not find where to register in {}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("Could not write {}: {}", path.display(), err))
+}
+
+/// Creates day `day` under `root`, returning the path of its module. Every
+/// check happens before the first write, so a failure leaves `root` as it
+/// was. Input and example files already there, as after `aoc fetch`, are
+/// kept.
+fn scaffold(root: &Path, day: u8) -> Result<PathBuf, String> {
+    let dd = format!("{:02}", day);
+    let module = root.join(format!("src/days/d{}.rs", dd));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+    let mod_rs = root.join("src/days/mod.rs");
+    let mod_content = registered(&mod_rs, "pub mod d", &format!("pub mod d{};", dd))?;
+    let registry = root.join("src/registry.rs");
+    let registry_content = registered(
+        &registry,
+        "    &Entry::<d",
+        &format!("    &Entry::<d{}::Day{}>(PhantomData),", dd, dd),
+    )?;
+
+    let source = TEMPLATE
+        .replace("{{DD}}", &dd)
+        .replace("{{DAY}}", &day.to_string());
+    write(&module, &source)?;
+    write(&mod_rs, &mod_content)?;
+    write(&registry, &registry_content)?;
+    for path in &[aoc::input_path(day), format!("examples/example{}.txt", dd)] {
+        let path = root.join(path);
+        if !path.exists() {
+            write(&path, "")?;
+        }
+    }
+    Ok(module)
+}
+
+/// Generates the solver module of a new day from the template, registers it
+/// and creates its empty input and example files.
+pub fn new_day(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not between 1 and 25", day));
+    }
+    let root = Path::new(args.value("root").unwrap_or("."));
+    let module = scaffold(root, day)?;
+    println!("Created day {} in {}", day, module.display());
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted() {
+        let content = "pub mod d01;\npub mod d13;\n";
+        assert_eq!(
+            insert_sorted(content, "pub mod d", "pub mod d14;").unwrap(),
+            "pub mod d01;\npub mod d13;\npub mod d14;\n"
+        );
+        assert_eq!(
+            insert_sorted(content, "pub mod d", "pub mod d05;").unwrap(),
+            "pub mod d01;\npub mod d05;\npub mod d13;\n"
+        );
+        assert_eq!(
+            insert_sorted(content, "pub mod d", "pub mod d00;").unwrap(),
+            "pub mod d00;\npub mod d01;\npub mod d13;\n"
+        );
+        assert_eq!(insert_sorted("", "pub mod d", "pub mod d05;"), None);
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        for dir in &["src/days", "input", "examples"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        let mod_rs = "pub mod d01;\n";
+        let registry =
+            "static ALL: &[&dyn Runner] = &[\n    &Entry::<d01::Day01>(PhantomData),\n];\n";
+        fs::write(root.join("src/days/mod.rs"), mod_rs).unwrap();
+        fs::write(root.join("src/registry.rs"), registry).unwrap();
+        let input = root.join(aoc::input_path(2));
+        fs::write(&input, "fetched\n").unwrap();
+
+        let module = scaffold(&root, 2);
+        let content = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        let created = (
+            content("src/days/mod.rs"),
+            content("src/registry.rs"),
+            fs::read_to_string(&input).unwrap(),
+            root.join("examples/example02.txt").exists(),
+        );
+        // A second run fails before writing anything.
+        fs::remove_file(root.join("src/days/d02.rs")).unwrap();
+        let rerun = scaffold(&root, 2);
+        let untouched = !root.join("src/days/d02.rs").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(module, Ok(root.join("src/days/d02.rs")));
+        assert_eq!(created.0, "pub mod d01;\npub mod d02;\n");
+        assert!(created
+            .1
+            .contains("    &Entry::<d02::Day02>(PhantomData),\n];"));
+        assert_eq!(created.2, "fetched\n");
+        assert!(created.3);
+        assert!(rerun
+            .unwrap_err()
+            .contains("already contains 'pub mod d02;'"));
+        assert!(untouched);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::args::Args;
+use crate::fetch;
+use aoc::client::Verdict;
+use aoc::guesses::{self, Guess, Record};
+use aoc::input::Source;
+use aoc::{registry, Part};
+use log::error;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn load_guesses(filename: &str) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(filename) {
+        Ok(content) => guesses::parse(&content).map_err(|err| err.in_file(filename).to_string()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("Could not load {}: {}", filename, err)),
+    }
+}
+
+fn record_guess(filename: &str, record: &Record) -> Result<(), String> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)
+        .and_then(|mut file| writeln!(file, "{}", record))
+        .map_err(|err| format!("Could not record the guess in {}: {}", filename, err))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Solves one part and submits its answer, unless the guesses file shows the
+/// submission would be pointless or come before the website accepts answers
+/// again.
+pub fn submit(args: &Args) -> Result<bool, String> {
+    let day: u8 = args.parse_value("day")?.ok_or("--day is required")?;
+    let part: u8 = args.parse_value("part")?.ok_or("--part is required")?;
+    let part = Part::new(part).ok_or(format!("Unknown part {}", part))?;
+    let runner = registry::get(day).ok_or(format!("No solver registered for day {}", day))?;
+    let input = args
+        .value("input")
+        .map_or_else(|| aoc::input_path(day), str::to_string);
+    let parsed = Source::from_arg(&input)
+        .load_with(runner)
+        .map_err(|err| err.to_string())?;
+    let answer = match parsed.solve(part) {
+        Some(answer) => answer.to_string(),
+        None => {
+            error!("Day {} part {}: no solution found", day, part);
+            return Ok(false);
+        }
+    };
+
+    let filename = args.value("guesses").unwrap_or(guesses::GUESSES_PATH);
+    let previous = load_guesses(filename)?;
+    if let Some(guess) = guesses::previous(&previous, day, part, &answer) {
+        println!(
+            "Day {} part {}: not submitting {}, {} was already judged: {}",
+            day, part, answer, guess.answer, guess.verdict
+        );
+        return Ok(guess.verdict == Verdict::Right && guess.answer == answer);
+    }
+    let now = now();
+    if let Some(deadline) = guesses::wait_until(&previous, now) {
+        return Err(format!(
+            "Not submitting {} yet, the website refuses answers for {}s more",
+            answer,
+            deadline - now
+        ));
+    }
+
+    let verdict = fetch::client(args)?
+        .submit(day, part, &answer)
+        .map_err(|err| err.to_string())?;
+    println!("Day {} part {}: {}, {}", day, part, answer, verdict);
+    match verdict {
+        Verdict::Wait(seconds) => record_guess(filename, &Record::wait(day, part, now, seconds))?,
+        _ if verdict.keyword().is_some() => record_guess(
+            filename,
+            &Record::Guess(Guess {
+                day,
+                part,
+                verdict: verdict.clone(),
+                answer,
+            }),
+        )?,
+        _ => {}
+    }
+    Ok(verdict == Verdict::Right)
+}
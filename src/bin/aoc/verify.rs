@@ -0,0 +1,83 @@
+use crate::args::Args;
+use crate::output::describe;
+use aoc::answers::{self, Expected};
+use aoc::input::Source;
+use aoc::registry::{self, Parsed};
+use aoc::Part;
+use log::warn;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+type Loaded = Result<Box<dyn Parsed>, String>;
+
+fn check(entry: &Expected, parsed: &Loaded) -> Result<(), String> {
+    let parsed = parsed.as_ref().map_err(String::clone)?;
+    match parsed.solve(entry.part) {
+        Some(answer) if answer.to_string() == entry.answer => Ok(()),
+        answer => Err(format!(
+            "expected: {}\n    actual:   {}",
+            entry.answer,
+            describe(&answer)
+        )),
+    }
+}
+
+/// Runs every solver against the answers file and reports each mismatch.
+pub fn verify(args: &Args) -> Result<bool, String> {
+    let filename = args.value("answers").unwrap_or(answers::ANSWERS_PATH);
+    let content = fs::read_to_string(filename)
+        .map_err(|err| format!("Could not load {}: {}", filename, err))?;
+    let entries = answers::parse(&content).map_err(|err| err.in_file(filename).to_string())?;
+
+    let mut loaded: HashMap<(u8, &str), Loaded> = HashMap::new();
+    let mut failed = 0;
+    for entry in &entries {
+        let runner = match registry::get(entry.day) {
+            Some(runner) => runner,
+            None => {
+                println!(
+                    "FAIL  day {:>2} part {}  no solver registered",
+                    entry.day, entry.part
+                );
+                failed += 1;
+                continue;
+            }
+        };
+        let parsed = loaded
+            .entry((entry.day, entry.input.as_str()))
+            .or_insert_with(|| {
+                Source::from_arg(&entry.input)
+                    .load_with(runner)
+                    .map_err(|err| err.to_string())
+            });
+        match check(entry, parsed) {
+            Ok(()) => println!(
+                "PASS  day {:>2} part {}  {}",
+                entry.day, entry.part, entry.input
+            ),
+            Err(diff) => {
+                println!(
+                    "FAIL  day {:>2} part {}  {}",
+                    entry.day, entry.part, entry.input
+                );
+                println!("    {}", diff);
+                failed += 1;
+            }
+        }
+    }
+
+    let covered: HashSet<(u8, Part)> = entries
+        .iter()
+        .map(|entry| (entry.day, entry.part))
+        .collect();
+    for runner in registry::all() {
+        for part in &Part::ALL {
+            if !covered.contains(&(runner.day(), *part)) {
+                warn!("No expected answer for day {} part {}", runner.day(), part);
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", entries.len() - failed, failed);
+    Ok(failed == 0)
+}
@@ -0,0 +1,176 @@
+use crate::args::Args;
+use crate::output::describe;
+use aoc::input::Source;
+use aoc::registry::{self, Runner};
+use aoc::{Answer, Part};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+const DEFAULT_INTERVAL_MS: u64 = 500;
+const EXAMPLES_DIR: &str = "examples";
+
+/// The input of `day` followed by its example fixtures in `examples`,
+/// `exampleNN.txt` and variants such as `exampleNN-2.txt`.
+fn watched_files(day: u8, input: &str, examples: &Path) -> Vec<PathBuf> {
+    let prefix = format!("example{:02}", day);
+    let mut examples: Vec<PathBuf> = fs::read_dir(examples)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    examples.sort();
+    let mut files = vec![PathBuf::from(input)];
+    files.extend(examples);
+    files
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Parses and solves one file, returning the lines reporting the answers and
+/// marking those that differ from the previous run of the same file.
+fn run_file(
+    runner: &dyn Runner,
+    path: &Path,
+    previous: &mut HashMap<(PathBuf, Part), Option<Answer>>,
+    highlight: bool,
+) -> Vec<String> {
+    let start = Instant::now();
+    let parsed = match Source::File(path.to_path_buf()).load_with(runner) {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![err.to_string()],
+    };
+    let mut lines = vec![format!(
+        "{}  parsed in {:.1?}",
+        path.display(),
+        start.elapsed()
+    )];
+    for part in &Part::ALL {
+        let start = Instant::now();
+        let answer = parsed.solve(*part);
+        let duration = start.elapsed();
+        let mut text = describe(&answer);
+        match previous.insert((path.to_path_buf(), *part), answer.clone()) {
+            Some(before) if before != answer => {
+                if highlight {
+                    text = format!("\x1b[1;33m{}\x1b[0m", text);
+                }
+                text = format!("{} (was {})", text, describe(&before));
+            }
+            _ => {}
+        }
+        lines.push(format!(
+            "    part {}  {:>10}  {}",
+            part,
+            format!("{:.1?}", duration),
+            text
+        ));
+    }
+    lines
+}
+
+/// Re-runs a day on its input and examples every time one of them changes.
+pub fn watch(args: &Args) -> Result<bool, String> {
+    let day = args.parse_value("day")?.ok_or("--day is required")?;
+    let runner = registry::get(day).ok_or(format!("No solver registered for day {}", day))?;
+    let input = args
+        .value("input")
+        .map_or_else(|| aoc::input_path(day), str::to_string);
+    let interval =
+        Duration::from_millis(args.parse_value("interval")?.unwrap_or(DEFAULT_INTERVAL_MS));
+    let highlight = io::stdout().is_terminal();
+
+    let mut seen: HashMap<PathBuf, Option<SystemTime>> = HashMap::new();
+    let mut previous = HashMap::new();
+    println!("Watching day {}, press Ctrl-C to stop", day);
+    loop {
+        let changed: Vec<(PathBuf, Option<SystemTime>)> =
+            watched_files(day, &input, Path::new(EXAMPLES_DIR))
+                .into_iter()
+                .map(|path| {
+                    let time = modified(&path);
+                    (path, time)
+                })
+                .filter(|(path, time)| seen.get(path) != Some(time))
+                .collect();
+        if !changed.is_empty() {
+            println!();
+            for (path, time) in changed {
+                for line in run_file(runner, &path, &mut previous, highlight) {
+                    println!("{}", line);
+                }
+                seen.insert(path, time);
+            }
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watched_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in &[
+            "example07.txt",
+            "example07-2.txt",
+            "example17.txt",
+            "example07.json",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        let files = watched_files(7, "inputs/07.txt", &dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("inputs/07.txt"),
+                dir.join("example07-2.txt"),
+                dir.join("example07.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_file() {
+        let runner = registry::get(1).unwrap();
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let mut previous = HashMap::new();
+        fs::write(&path, include_str!("../../../examples/example01.txt")).unwrap();
+        let first = run_file(runner, &path, &mut previous, false);
+        fs::write(&path, "1010\n1010\n").unwrap();
+        let second = run_file(runner, &path, &mut previous, true);
+        let third = run_file(runner, &path, &mut previous, false);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(first.len(), 3);
+        assert!(first[1].ends_with("  514579"), "{}", first[1]);
+        assert!(
+            second[1].ends_with("  \x1b[1;33m1020100\x1b[0m (was 514579)"),
+            "{}",
+            second[1]
+        );
+        assert!(
+            second[2].ends_with("  \x1b[1;33mno solution found\x1b[0m (was 241861950)"),
+            "{}",
+            second[2]
+        );
+        assert!(third[1].ends_with("  1020100"), "{}", third[1]);
+        assert!(third[2].ends_with("  no solution found"), "{}", third[2]);
+    }
+}
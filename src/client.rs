@@ -0,0 +1,334 @@
+use crate::Part;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2020;
+
+#[derive(Debug)]
+pub enum ClientError {
+    Status { url: String, status: u16 },
+    Transport { url: String, message: String },
+    Io { path: String, error: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Status { url, status } => write!(f, "{} answered HTTP {}", url, status),
+            ClientError::Transport { url, message } => {
+                write!(f, "could not reach {}: {}", url, message)
+            }
+            ClientError::Io { path, error } => write!(f, "could not write {}: {}", path, error),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+/// Talks to the puzzle website, authenticated by the session cookie of a
+/// logged in browser.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// Whether `fetch_cached` had to download the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer, with the remaining
+    /// seconds when the page tells them. The answer was not judged.
+    Wait(Option<u64>),
+    /// The part is already solved, or its first part is not.
+    WrongLevel,
+    /// A page none of the other verdicts matched, stripped of its markup.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page answering a submission.
+    pub fn parse(page: &str) -> Self {
+        lazy_static! {
+            static ref TAG: Regex = Regex::new("<[^>]*>").unwrap();
+            static ref WAIT: Regex =
+                Regex::new("You have (?:(\\d+)m )?(\\d+)s left to wait").unwrap();
+        }
+        let text = TAG.replace_all(page, "");
+        if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::Wait(WAIT.captures(&text).map(|cap| {
+                let minutes: u64 = cap.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                minutes * 60 + cap[2].parse::<u64>().unwrap()
+            }))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    /// The name under which the verdict is recorded, `None` when it does not
+    /// judge the answer.
+    pub fn keyword(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Right => Some("right"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::TooHigh => Some("too-high"),
+            Verdict::TooLow => Some("too-low"),
+            Verdict::Wait(_) | Verdict::WrongLevel | Verdict::Unknown(_) => None,
+        }
+    }
+
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "right" => Some(Verdict::Right),
+            "wrong" => Some(Verdict::Wrong),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right answer"),
+            Verdict::Wrong => write!(f, "wrong answer"),
+            Verdict::TooHigh => write!(f, "wrong answer, too high"),
+            Verdict::TooLow => write!(f, "wrong answer, too low"),
+            Verdict::Wait(Some(seconds)) => write!(f, "too soon, wait {}s", seconds),
+            Verdict::Wait(None) => write!(f, "too soon, wait before retrying"),
+            Verdict::WrongLevel => write!(f, "not the current part, already solved?"),
+            Verdict::Unknown(text) => write!(f, "unexpected response: {}", text),
+        }
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| http_error(url, err))?
+            .into_string()
+            .map_err(|err| ClientError::Transport {
+                url: url.to_string(),
+                message: err.to_string(),
+            })
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Posts `answer` for `part` of `day` and reads back the verdict.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.number().to_string();
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|err| http_error(&url, err))?
+            .into_string()
+            .map(|page| Verdict::parse(&page))
+            .map_err(|err| ClientError::Transport {
+                url,
+                message: err.to_string(),
+            })
+    }
+
+    /// Downloads the input of `day` into `path`, unless a previous download is
+    /// already there. Empty files, as created by `aoc new`, do not count.
+    pub fn fetch_cached(&self, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+        if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.fetch_input(day)?;
+        fs::write(path, input).map_err(|error| ClientError::Io {
+            path: path.display().to_string(),
+            error,
+        })?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+fn http_error(url: &str, err: ureq::Error) -> ClientError {
+    match err {
+        ureq::Error::Status(status, _) => ClientError::Status {
+            url: url.to_string(),
+            status,
+        },
+        ureq::Error::Transport(transport) => ClientError::Transport {
+            url: url.to_string(),
+            message: transport.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serves `responses` in order on a local port, sending back the request
+    /// line, headers and body of every request received.
+    pub(crate) fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                sender.send(request).unwrap();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    #[test]
+    fn test_fetch_cached() {
+        let (url, requests) = stub_server(vec![(200, "1721\n979\n")]);
+        let client = Client::new(&url, "secret");
+        let path = std::env::temp_dir().join(format!("aoc-fetch-{}.txt", std::process::id()));
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(client.fetch_cached(1, &path).unwrap(), Fetched::Downloaded);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=secret"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+        assert_eq!(client.fetch_cached(1, &path).unwrap(), Fetched::Cached);
+        assert!(requests.try_recv().is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_status_error() {
+        let (url, _requests) = stub_server(vec![(400, "Please log in")]);
+        let client = Client::new(&url, "expired");
+        match client.fetch_input(3) {
+            Err(ClientError::Status { status: 400, .. }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = stub_server(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (
+                200,
+                "<p>That's not the right answer; your answer is too <em>low</em>.</p>",
+            ),
+        ]);
+        let client = Client::new(&url, "secret");
+        assert_eq!(
+            client.submit(1, Part::One, "514579").unwrap(),
+            Verdict::Right
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2020/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=1&answer=514579"));
+        assert_eq!(client.submit(1, Part::Two, "42").unwrap(), Verdict::TooLow);
+    }
+
+    #[test]
+    fn test_verdict_parse() {
+        assert_eq!(
+            Verdict::parse("<p>That's not the right answer.  If you're stuck...</p>"),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 1m 12s left to wait."),
+            Verdict::Wait(Some(72))
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            Verdict::parse("<main>\n<p>Down for maintenance</p>\n</main>"),
+            Verdict::Unknown("Down for maintenance".to_string())
+        );
+    }
+}
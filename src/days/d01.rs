@@ -0,0 +1,764 @@
+use crate::parse::{parse_lines, parse_number};
+use crate::{ParseError, Query, Solver, Variant};
+use log::warn;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter;
+
+static EXPECTED_VALUE: i32 = 2020;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "sum",
+            args: "<k> [<target>]",
+            help: "k entries summing to <target>, 2020 by default, and their product",
+        },
+        Query {
+            name: "all",
+            args: "<k> [<target>]",
+            help: "every combination of k entries summing to <target>, and their count",
+        },
+        Query {
+            name: "nearest",
+            args: "<k> [<target>]",
+            help: "k entries whose sum is nearest to <target>, and its distance",
+        },
+    ];
+    const VARIANTS1: &'static [Variant<Vec<i32>, i64>] =
+        &[("hash-set", |numbers| part1_hash_set(numbers))];
+    const VARIANTS2: &'static [Variant<Vec<i32>, i64>] =
+        &[("two-pointer", |numbers| part2_two_pointer(numbers))];
+    type Input = Vec<i32>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<i64> {
+        part2(input)
+    }
+
+    fn query(input: &Self::Input, name: &str, arg: &str) -> Option<Result<String, String>> {
+        match name {
+            "sum" => {
+                Some(
+                    parse_sum_arg(arg).and_then(|(k, target)| match k_sum(input, k, target) {
+                        Some(combination) => Ok(combination.to_string()),
+                        None => Err(format!("No {} entries sum to {}", k, target)),
+                    }),
+                )
+            }
+            "all" => Some(parse_sum_arg(arg).and_then(|(k, target)| {
+                let matches = all_k_sums(input, k, target);
+                if matches.is_empty() {
+                    return Err(format!("No {} entries sum to {}", k, target));
+                }
+                let count = matches
+                    .iter()
+                    .try_fold(0u64, |count, found| count.checked_add(found.ways?));
+                let mut report = match count {
+                    Some(count) => {
+                        format!(
+                            "{} entries summing to {}: {} combinations",
+                            k, target, count
+                        )
+                    }
+                    None => format!(
+                        "{} entries summing to {}: too many combinations to count",
+                        k, target
+                    ),
+                };
+                for found in &matches {
+                    report.push_str(&format!("\n  {}", found));
+                }
+                Ok(report)
+            })),
+            "nearest" => Some(parse_sum_arg(arg).and_then(|(k, target)| {
+                nearest_k_sum(input, k, target)
+                    .map(|nearest| nearest.to_string())
+                    .ok_or(format!("There are fewer than {} entries", k))
+            })),
+            _ => None,
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut numbers: Vec<i32> = parse_lines(input, |line| parse_number(line, 1))?;
+    numbers.sort_unstable();
+    Ok(numbers)
+}
+
+fn part1_hash_set(numbers: &[i32]) -> Option<i64> {
+    let target = i64::from(EXPECTED_VALUE);
+    let mut seen = HashSet::new();
+    for &value in numbers {
+        let value = i64::from(value);
+        if seen.contains(&(target - value)) {
+            return Some(value * (target - value));
+        }
+        seen.insert(value);
+    }
+    None
+}
+
+fn part2_two_pointer(sorted_numbers: &[i32]) -> Option<i64> {
+    let target = i64::from(EXPECTED_VALUE);
+    let entry = |idx: usize| i64::from(sorted_numbers[idx]);
+    for first_idx in 0..sorted_numbers.len() {
+        let (mut low, mut high) = (first_idx + 1, sorted_numbers.len().checked_sub(1)?);
+        while low < high {
+            let sum = entry(first_idx) + entry(low) + entry(high);
+            match sum.cmp(&target) {
+                Ordering::Less => low += 1,
+                Ordering::Greater => high -= 1,
+                Ordering::Equal => return (entry(first_idx) * entry(low)).checked_mul(entry(high)),
+            }
+        }
+    }
+    None
+}
+
+/// Parses an entry for `stream`, which only takes entries between 0 and the
+/// expected sum, as in the puzzle.
+pub fn parse_entry(line: &str) -> Result<i32, ParseError> {
+    match parse_number(line, 1)? {
+        entry if (0..=EXPECTED_VALUE).contains(&entry) => Ok(entry),
+        _ => Err(ParseError::new(
+            1,
+            format!("an entry between 0 and {}", EXPECTED_VALUE),
+            line,
+        )),
+    }
+}
+
+/// Solves both parts in a single pass over `numbers`, in memory bounded by the
+/// expected sum rather than the number of entries. Entries must be between 0
+/// and the expected sum, as `parse_entry` ensures, and reading stops once both
+/// answers are known.
+pub fn stream<E, I>(numbers: I) -> Result<(Option<i32>, Option<i32>), E>
+where
+    I: IntoIterator<Item = Result<i32, E>>,
+{
+    let target = EXPECTED_VALUE as usize;
+    let mut seen = vec![false; target + 1];
+    // Product of a pair of distinct entries seen so far, indexed by their sum.
+    let mut pairs: Vec<Option<i32>> = vec![None; target + 1];
+    let (mut two, mut three) = (None, None);
+    for number in numbers {
+        let value = number?;
+        debug_assert!((0..=EXPECTED_VALUE).contains(&value));
+        let value = value as usize;
+        if two.is_none() && seen[target - value] {
+            two = Some((value * (target - value)) as i32);
+        }
+        if three.is_none() {
+            three = pairs[target - value].map(|product| product * value as i32);
+        }
+        if two.is_some() && three.is_some() {
+            break;
+        }
+        if seen[value] {
+            // Only the pair with its own duplicate is new.
+            if 2 * value <= target && pairs[2 * value].is_none() {
+                pairs[2 * value] = Some((value * value) as i32);
+            }
+            continue;
+        }
+        for other in (0..=target - value).filter(|&other| seen[other]) {
+            pairs[value + other].get_or_insert((value * other) as i32);
+        }
+        seen[value] = true;
+    }
+    Ok((two, three))
+}
+
+/// Entries of the expense report summing to a target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    /// The entries, in increasing order.
+    pub entries: Vec<i32>,
+    /// The product of the entries, `None` if it overflows.
+    pub product: Option<i64>,
+}
+
+impl Combination {
+    fn new(entries: Vec<i32>) -> Self {
+        let product = entries
+            .iter()
+            .try_fold(1i64, |product, &entry| product.checked_mul(entry.into()));
+        Combination { entries, product }
+    }
+}
+
+impl fmt::Display for Combination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries: Vec<String> = self.entries.iter().map(i32::to_string).collect();
+        let sum: i64 = self.entries.iter().map(|&entry| i64::from(entry)).sum();
+        write!(f, "{} = {}, product ", entries.join(" + "), sum)?;
+        match self.product {
+            Some(product) => write!(f, "{}", product),
+            None => write!(f, "overflows"),
+        }
+    }
+}
+
+/// Finds `k` distinct entries of `sorted_numbers` summing to `target`.
+///
+/// The last two entries are found by a two-pointer search, so this takes
+/// O(n^(k-1)) steps, pruned by the smallest and largest sums still reachable.
+pub fn k_sum(sorted_numbers: &[i32], k: usize, target: i64) -> Option<Combination> {
+    let mut entries = Vec::with_capacity(k);
+    if find_sum(sorted_numbers, k, target, &mut entries) {
+        Some(Combination::new(entries))
+    } else {
+        None
+    }
+}
+
+/// Pushes to `entries` `k` entries of `sorted_numbers` summing to `target`,
+/// returning whether there are some.
+fn find_sum(sorted_numbers: &[i32], k: usize, target: i64, entries: &mut Vec<i32>) -> bool {
+    let len = sorted_numbers.len();
+    if k > len {
+        return false;
+    }
+    match k {
+        0 => target == 0,
+        1 => match i32::try_from(target) {
+            Ok(value) if sorted_numbers.binary_search(&value).is_ok() => {
+                entries.push(value);
+                true
+            }
+            _ => false,
+        },
+        2 => {
+            let (mut low, mut high) = (0, len - 1);
+            while low < high {
+                let sum = i64::from(sorted_numbers[low]) + i64::from(sorted_numbers[high]);
+                match sum.cmp(&target) {
+                    Ordering::Less => low += 1,
+                    Ordering::Greater => high -= 1,
+                    Ordering::Equal => {
+                        entries.extend([sorted_numbers[low], sorted_numbers[high]]);
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+        _ => {
+            let sum = |numbers: &[i32]| numbers.iter().map(|&n| i64::from(n)).sum::<i64>();
+            let largest_rest = sum(&sorted_numbers[len - (k - 1)..]);
+            for idx in 0..=len - k {
+                let value = sorted_numbers[idx];
+                // Equal values would only find the same combinations again.
+                if idx > 0 && sorted_numbers[idx - 1] == value {
+                    continue;
+                }
+                if sum(&sorted_numbers[idx..idx + k]) > target {
+                    break;
+                }
+                if i64::from(value) + largest_rest < target {
+                    continue;
+                }
+                entries.push(value);
+                if find_sum(
+                    &sorted_numbers[idx + 1..],
+                    k - 1,
+                    target - i64::from(value),
+                    entries,
+                ) {
+                    return true;
+                }
+                entries.pop();
+            }
+            false
+        }
+    }
+}
+
+/// The entries whose sum is nearest to a target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nearest {
+    pub combination: Combination,
+    /// The sum minus the target, negative when the sum is below it.
+    pub distance: i64,
+}
+
+impl fmt::Display for Nearest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, distance {:+}", self.combination, self.distance)
+    }
+}
+
+/// Finds `k` distinct entries of `sorted_numbers` whose sum is nearest to
+/// `target`, above or below, or `None` if there are fewer than `k` entries.
+///
+/// Like `k_sum`, this is a two-pointer search under the first `k - 2` entries,
+/// a branch being cut short when all its sums are on the same side of the
+/// target.
+pub fn nearest_k_sum(sorted_numbers: &[i32], k: usize, target: i64) -> Option<Nearest> {
+    let mut best = None;
+    let mut chosen = Vec::with_capacity(k);
+    find_nearest_sum(sorted_numbers, k, target, &mut chosen, &mut best);
+    best
+}
+
+fn total(entries: &[i32]) -> i64 {
+    entries.iter().map(|&n| i64::from(n)).sum()
+}
+
+/// Replaces `best` with `chosen` and `rest` if their sum is nearer to `target`.
+fn offer(chosen: &[i32], rest: &[i32], target: i64, best: &mut Option<Nearest>) {
+    let distance = total(chosen) + total(rest) - target;
+    if best
+        .as_ref()
+        .is_none_or(|best| distance.abs() < best.distance.abs())
+    {
+        let entries = chosen.iter().chain(rest).copied().collect();
+        *best = Some(Nearest {
+            combination: Combination::new(entries),
+            distance,
+        });
+    }
+}
+
+/// Offers to `best` the combinations of `chosen` with `k` entries of
+/// `sorted_numbers` whose sum is nearest to `target`.
+fn find_nearest_sum(
+    sorted_numbers: &[i32],
+    k: usize,
+    target: i64,
+    chosen: &mut Vec<i32>,
+    best: &mut Option<Nearest>,
+) {
+    let len = sorted_numbers.len();
+    if k > len || best.as_ref().is_some_and(|best| best.distance == 0) {
+        return;
+    }
+    // What the `k` entries should sum to.
+    let rest = target - total(chosen);
+    match k {
+        0 => offer(chosen, &[], target, best),
+        1 => {
+            let idx = sorted_numbers.partition_point(|&n| i64::from(n) < rest);
+            for idx in idx.saturating_sub(1)..(idx + 1).min(len) {
+                offer(chosen, &sorted_numbers[idx..=idx], target, best);
+            }
+        }
+        2 => {
+            let (mut low, mut high) = (0, len - 1);
+            while low < high {
+                let pair = [sorted_numbers[low], sorted_numbers[high]];
+                offer(chosen, &pair, target, best);
+                match total(&pair).cmp(&rest) {
+                    Ordering::Less => low += 1,
+                    Ordering::Greater => high -= 1,
+                    Ordering::Equal => return,
+                }
+            }
+        }
+        _ => {
+            let largest_rest = &sorted_numbers[len - (k - 1)..];
+            for idx in 0..=len - k {
+                let value = sorted_numbers[idx];
+                if idx > 0 && sorted_numbers[idx - 1] == value {
+                    continue;
+                }
+                // The smallest sums from here are all above the target.
+                let smallest = &sorted_numbers[idx..idx + k];
+                if total(smallest) >= rest {
+                    offer(chosen, smallest, target, best);
+                    break;
+                }
+                // The largest sums with this value are all below the target.
+                chosen.push(value);
+                if i64::from(value) + total(largest_rest) <= rest {
+                    offer(chosen, largest_rest, target, best);
+                } else {
+                    find_nearest_sum(&sorted_numbers[idx + 1..], k - 1, target, chosen, best);
+                }
+                chosen.pop();
+            }
+        }
+    }
+}
+
+/// The combinations of entries having the same values and summing to a
+/// target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub combination: Combination,
+    /// The number of combinations of distinct entries having these values,
+    /// `None` if it overflows.
+    pub ways: Option<u64>,
+    /// The values of the combination appearing more than once in the report.
+    pub duplicates: Vec<i32>,
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.combination)?;
+        if !self.duplicates.is_empty() {
+            let duplicates: Vec<String> = self.duplicates.iter().map(i32::to_string).collect();
+            write!(f, " (duplicate values {}", duplicates.join(", "))?;
+            match self.ways {
+                Some(1) => {}
+                Some(ways) => write!(f, ", {} combinations of entries", ways)?,
+                None => write!(f, ", too many combinations of entries to count")?,
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds every combination of `k` distinct entries of `sorted_numbers`
+/// summing to `target`. Entries with equal values are told apart by their
+/// position, but combinations differing only by such entries are grouped in a
+/// single `Match`.
+pub fn all_k_sums(sorted_numbers: &[i32], k: usize, target: i64) -> Vec<Match> {
+    // Each distinct value, with how many entries have it.
+    let mut values: Vec<(i32, usize)> = Vec::new();
+    for &value in sorted_numbers {
+        match values.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => values.push((value, 1)),
+        }
+    }
+    let mut matches = Vec::new();
+    let mut chosen = Vec::with_capacity(k);
+    find_all_sums(&values, k, target, &mut chosen, &mut matches);
+    matches
+}
+
+/// Pushes to `matches` every way of completing the `chosen` indices of
+/// `values` with `k` entries of values from `values` summing to `target`.
+fn find_all_sums(
+    values: &[(i32, usize)],
+    k: usize,
+    target: i64,
+    chosen: &mut Vec<(i32, usize, usize)>,
+    matches: &mut Vec<Match>,
+) {
+    if k == 0 {
+        if target == 0 {
+            matches.push(Match {
+                combination: Combination::new(
+                    chosen
+                        .iter()
+                        .flat_map(|&(value, _, used)| iter::repeat_n(value, used))
+                        .collect(),
+                ),
+                ways: chosen.iter().try_fold(1u64, |ways, &(_, count, used)| {
+                    ways.checked_mul(binomial(count, used)?)
+                }),
+                duplicates: chosen
+                    .iter()
+                    .filter(|&&(_, count, _)| count > 1)
+                    .map(|&(value, _, _)| value)
+                    .collect(),
+            });
+        }
+        return;
+    }
+    let largest = match values.last() {
+        Some(&(largest, _)) => i64::from(largest),
+        None => return,
+    };
+    for (idx, &(value, count)) in values.iter().enumerate() {
+        let value64 = i64::from(value);
+        // Values are increasing, so the sum can only grow from here.
+        if value64 * k as i64 > target {
+            break;
+        }
+        if value64 + largest * (k as i64 - 1) < target {
+            continue;
+        }
+        for used in 1..=count.min(k) {
+            chosen.push((value, count, used));
+            find_all_sums(
+                &values[idx + 1..],
+                k - used,
+                target - value64 * used as i64,
+                chosen,
+                matches,
+            );
+            chosen.pop();
+        }
+    }
+}
+
+/// The number of ways to pick `k` out of `n` items, `None` if it overflows.
+fn binomial(n: usize, k: usize) -> Option<u64> {
+    // Every intermediate value is a smaller binomial, so fits if the result
+    // does, and their products by `n` fit in a `u128`.
+    let (n, k) = (n as u128, k.min(n - k) as u128);
+    (0..k).try_fold(1u64, |ways, i| {
+        u64::try_from(u128::from(ways) * (n - i) / (i + 1)).ok()
+    })
+}
+
+/// Parses the `<k> [<target>]` argument of the `sum` and `all` queries.
+fn parse_sum_arg(arg: &str) -> Result<(usize, i64), String> {
+    let mut words = arg.split_whitespace();
+    let k = match words.next().map(str::parse) {
+        Some(Ok(k)) if k > 0 => k,
+        _ => return Err(format!("Invalid number of entries in '{}'", arg)),
+    };
+    let target = match words.next().map(str::parse) {
+        None => EXPECTED_VALUE.into(),
+        Some(Ok(target)) => target,
+        Some(Err(_)) => return Err(format!("Invalid target in '{}'", arg)),
+    };
+    match words.next() {
+        Some(extra) => Err(format!("Unexpected '{}' after the target", extra)),
+        None => Ok((k, target)),
+    }
+}
+
+/// The `k` entries summing to `EXPECTED_VALUE`, warning about the nearest sum
+/// when there are none.
+fn expected_sum(sorted_numbers: &[i32], k: usize) -> Option<Combination> {
+    let target = EXPECTED_VALUE.into();
+    let found = k_sum(sorted_numbers, k, target);
+    if found.is_none() {
+        match nearest_k_sum(sorted_numbers, k, target) {
+            Some(nearest) => warn!("No {} entries sum to {}, nearest: {}", k, target, nearest),
+            None => warn!("No {} entries sum to {}", k, target),
+        }
+    }
+    found
+}
+
+fn part1(sorted_numbers: &[i32]) -> Option<i64> {
+    expected_sum(sorted_numbers, 2)?.product
+}
+
+fn part2(sorted_numbers: &[i32]) -> Option<i64> {
+    expected_sum(sorted_numbers, 3)?.product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day01::parse(include_str!("../../examples/example01.txt")).unwrap();
+        assert_eq!(Day01::part1(&input), Some(514579));
+        assert_eq!(Day01::part2(&input), Some(241861950));
+    }
+
+    #[test]
+    fn test_variants_overflow() {
+        let numbers = Day01::parse("2147483647\n-2147481627\n7\n").unwrap();
+        let product = Some(2147483647 * -2147481627);
+        assert_eq!(Day01::part1(&numbers), product);
+        assert_eq!(part1_hash_set(&numbers), product);
+        let numbers = Day01::parse("100000\n100000\n-197980\n").unwrap();
+        let product = Some(100000 * 100000 * -197980);
+        assert_eq!(Day01::part2(&numbers), product);
+        assert_eq!(part2_two_pointer(&numbers), product);
+        let numbers = Day01::parse("2147483647\n-2147483648\n2021\n").unwrap();
+        assert_eq!(Day01::part2(&numbers), None);
+        assert_eq!(part2_two_pointer(&numbers), None);
+    }
+
+    #[test]
+    fn test_stream() {
+        let numbers = [1721, 979, 366, 299, 675, 1456]
+            .iter()
+            .map(|&n| Ok::<_, ()>(n));
+        assert_eq!(stream(numbers), Ok((Some(514579), Some(241861950))));
+        let numbers = [1010, 5, 1010].iter().map(|&n| Ok::<_, ()>(n));
+        assert_eq!(stream(numbers), Ok((Some(1020100), None)));
+        let numbers = [1000, 1000, 20].iter().map(|&n| Ok::<_, ()>(n));
+        assert_eq!(stream(numbers), Ok((None, Some(20000000))));
+        assert_eq!(stream(vec![Ok(1), Err("bad line")]), Err("bad line"));
+        assert_eq!(parse_entry("2020"), Ok(2020));
+        assert!(parse_entry("2021").is_err());
+        assert!(parse_entry("-1").is_err());
+    }
+
+    #[test]
+    fn test_k_sum() {
+        let numbers = Day01::parse(include_str!("../../examples/example01.txt")).unwrap();
+        assert_eq!(
+            k_sum(&numbers, 4, 3100).unwrap().entries,
+            vec![299, 366, 979, 1456]
+        );
+        assert_eq!(k_sum(&numbers, 4, 3000), None);
+        assert_eq!(
+            k_sum(&numbers, 1, 366),
+            Some(Combination {
+                entries: vec![366],
+                product: Some(366)
+            })
+        );
+        assert_eq!(
+            k_sum(&numbers, 6, 5496).unwrap().product,
+            Some(1721 * 979 * 366 * 299 * 675 * 1456)
+        );
+        assert_eq!(k_sum(&numbers, 7, 5496), None);
+        assert_eq!(
+            k_sum(&[1010, 1010], 2, 2020).unwrap().product,
+            Some(1020100)
+        );
+        assert_eq!(k_sum(&[5, 1010], 2, 2020), None);
+        assert_eq!(k_sum(&[-5, 3, 7], 3, 5).unwrap().entries, vec![-5, 3, 7]);
+    }
+
+    #[test]
+    fn test_all_k_sums() {
+        let numbers = [5, 1010, 1010, 1010, 2015];
+        let matches = all_k_sums(&numbers, 2, 2020);
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    combination: Combination::new(vec![5, 2015]),
+                    ways: Some(1),
+                    duplicates: vec![],
+                },
+                Match {
+                    combination: Combination::new(vec![1010, 1010]),
+                    ways: Some(3),
+                    duplicates: vec![1010],
+                },
+            ]
+        );
+        let matches = all_k_sums(&numbers, 3, 2025);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            (matches[0].ways, &matches[0].duplicates),
+            (Some(3), &vec![1010])
+        );
+        assert_eq!(all_k_sums(&numbers, 4, 10), vec![]);
+        assert_eq!(all_k_sums(&numbers, 6, 5050), vec![]);
+
+        assert_eq!(binomial(300, 10), Some(1_398_320_233_241_701_770));
+        assert_eq!(binomial(300, 290), Some(1_398_320_233_241_701_770));
+        assert_eq!(binomial(300, 20), None);
+        assert_eq!((binomial(5, 0), binomial(5, 5)), (Some(1), Some(1)));
+        let numbers = vec![1010; 300];
+        let matches = all_k_sums(&numbers, 20, 20200);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].ways, None);
+        assert_eq!(
+            Day01::query(&numbers, "all", "20 20200")
+                .unwrap()
+                .unwrap()
+                .lines()
+                .next(),
+            Some("20 entries summing to 20200: too many combinations to count")
+        );
+        assert_eq!(all_k_sums(&numbers, 2, 2020)[0].ways, Some(44850));
+
+        let numbers = [1, 2, 2, 3, 3, 3, 4];
+        for target in 0..15 {
+            let mut triples = 0;
+            for i in 0..numbers.len() {
+                for j in i + 1..numbers.len() {
+                    for k in j + 1..numbers.len() {
+                        if numbers[i] + numbers[j] + numbers[k] == target {
+                            triples += 1;
+                        }
+                    }
+                }
+            }
+            let matches = all_k_sums(&numbers, 3, target.into());
+            let ways = matches.iter().map(|found| found.ways.unwrap()).sum::<u64>();
+            assert_eq!(ways, triples);
+        }
+
+        let numbers = Day01::parse(include_str!("../../examples/example01.txt")).unwrap();
+        for k in 1..=6 {
+            for found in all_k_sums(&numbers, k, 3100) {
+                assert_eq!(found.combination.entries.len(), k);
+                assert_eq!(found.combination.entries.iter().sum::<i32>(), 3100);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearest_k_sum() {
+        let numbers = [-7, 1, 4, 4, 9, 15, 22, 40];
+        for k in 0..=numbers.len() {
+            for target in -20..100 {
+                let nearest = nearest_k_sum(&numbers, k, target).unwrap();
+                let brute_force = (0..1u32 << numbers.len())
+                    .filter(|mask| mask.count_ones() as usize == k)
+                    .map(|mask| {
+                        let sum: i64 = (0..numbers.len())
+                            .filter(|idx| mask >> idx & 1 == 1)
+                            .map(|idx| i64::from(numbers[idx]))
+                            .sum();
+                        (sum - target).abs()
+                    })
+                    .min()
+                    .unwrap();
+                assert_eq!(
+                    nearest.distance.abs(),
+                    brute_force,
+                    "k {} target {}",
+                    k,
+                    target
+                );
+                assert_eq!(nearest.combination.entries.len(), k);
+                assert_eq!(
+                    total(&nearest.combination.entries) - target,
+                    nearest.distance
+                );
+            }
+        }
+        assert_eq!(nearest_k_sum(&numbers, 9, 0), None);
+    }
+
+    #[test]
+    fn test_sum_query() {
+        let numbers = Day01::parse(include_str!("../../examples/example01.txt")).unwrap();
+        assert_eq!(
+            Day01::query(&numbers, "sum", "2"),
+            Some(Ok("299 + 1721 = 2020, product 514579".to_string()))
+        );
+        assert_eq!(
+            Day01::query(&numbers, "sum", "2 1"),
+            Some(Err("No 2 entries sum to 1".to_string()))
+        );
+        assert_eq!(
+            Day01::query(&numbers, "nearest", "2 1"),
+            Some(Ok(
+                "299 + 366 = 665, product 109434, distance +664".to_string()
+            ))
+        );
+        assert_eq!(
+            Day01::query(&numbers, "nearest", "3 3500"),
+            Some(Ok(
+                "299 + 1456 + 1721 = 3476, product 749227024, distance -24".to_string()
+            ))
+        );
+        assert!(matches!(Day01::query(&numbers, "sum", "0"), Some(Err(_))));
+        assert!(matches!(Day01::query(&numbers, "sum", "2 x"), Some(Err(_))));
+        assert_eq!(
+            Day01::query(&vec![1000, 1000, 1010, 1010, 1020], "all", "2"),
+            Some(Ok("2 entries summing to 2020: 3 combinations\n  \
+                1000 + 1020 = 2020, product 1020000 (duplicate values 1000, 2 combinations of entries)\n  \
+                1010 + 1010 = 2020, product 1020100 (duplicate values 1010)"
+                .to_string()))
+        );
+    }
+}
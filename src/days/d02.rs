@@ -0,0 +1,578 @@
+use crate::parse::{lines, parse_lines, parse_number};
+use crate::{ParseError, Query, Solver};
+use lazy_static::lazy_static;
+use log::error;
+use regex::{Captures, Regex};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+
+/// The fields of a line before the password, which policies refer to as
+/// `$min`, `$max` and `$letter`.
+#[derive(Debug)]
+pub struct PasswordRule {
+    min: usize,
+    max: usize,
+    letter: char,
+}
+
+/// A number in a policy: either literal or a field of the line.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Number {
+    Field(NumberField),
+    Value(usize),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum NumberField {
+    #[serde(rename = "$min")]
+    Min,
+    #[serde(rename = "$max")]
+    Max,
+}
+
+/// A text in a policy: either literal or the letter of the line. Texts
+/// starting with `$` are fields, so that a misspelled one is an error.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Text {
+    Letter,
+    Value(String),
+}
+
+impl TryFrom<String> for Text {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        match text.as_str() {
+            "$letter" => Ok(Text::Letter),
+            field if field.starts_with('$') => {
+                Err(format!("unknown field {}, expected $letter", field))
+            }
+            _ => Ok(Text::Value(text)),
+        }
+    }
+}
+
+impl Number {
+    fn get(&self, rule: &PasswordRule) -> usize {
+        match self {
+            Number::Field(NumberField::Min) => rule.min,
+            Number::Field(NumberField::Max) => rule.max,
+            Number::Value(value) => *value,
+        }
+    }
+}
+
+impl Text {
+    fn get(&self, rule: &PasswordRule) -> String {
+        match self {
+            Text::Letter => rule.letter.to_string(),
+            Text::Value(value) => value.clone(),
+        }
+    }
+}
+
+/// A regex, compiled when the policy is loaded.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Pattern(Regex);
+
+impl TryFrom<String> for Pattern {
+    type Error = regex::Error;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Regex::new(&pattern).map(Pattern)
+    }
+}
+
+/// A password policy, as described in a policy file.
+///
+/// Each policy is an object with a single key naming it, e.g.
+/// `{"count": {"text": "$letter", "min": "$min", "max": "$max"}}`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Policy {
+    /// `text` occurs between `min` and `max` times, both included and
+    /// unbounded when missing.
+    Count {
+        text: Text,
+        min: Option<Number>,
+        max: Option<Number>,
+    },
+    /// `text` starts at the 1-based `position`.
+    At {
+        position: Number,
+        text: Text,
+    },
+    /// The password does not contain the text.
+    Forbid(Text),
+    /// The password matches the regex.
+    Regex(Pattern),
+    And(Vec<Policy>),
+    Or(Vec<Policy>),
+    /// An odd number of the policies hold.
+    Xor(Vec<Policy>),
+    Not(Box<Policy>),
+}
+
+impl Policy {
+    pub fn check(&self, rule: &PasswordRule, pass: &str) -> bool {
+        match self {
+            Policy::Count { text, min, max } => {
+                let occurrences = pass.matches(&text.get(rule)).count();
+                min.as_ref().is_none_or(|min| occurrences >= min.get(rule))
+                    && max.as_ref().is_none_or(|max| occurrences <= max.get(rule))
+            }
+            Policy::At { position, text } => match position.get(rule).checked_sub(1) {
+                Some(idx) => pass
+                    .char_indices()
+                    .nth(idx)
+                    .is_some_and(|(start, _)| pass[start..].starts_with(&text.get(rule))),
+                None => false,
+            },
+            Policy::Forbid(text) => !pass.contains(&text.get(rule)),
+            Policy::Regex(Pattern(regex)) => regex.is_match(pass),
+            Policy::And(policies) => policies.iter().all(|policy| policy.check(rule, pass)),
+            Policy::Or(policies) => policies.iter().any(|policy| policy.check(rule, pass)),
+            Policy::Xor(policies) => {
+                policies
+                    .iter()
+                    .filter(|policy| policy.check(rule, pass))
+                    .count()
+                    % 2
+                    == 1
+            }
+            Policy::Not(policy) => !policy.check(rule, pass),
+        }
+    }
+
+    /// Like `check`, but also says why the password is valid or not.
+    pub fn explain(&self, rule: &PasswordRule, pass: &str) -> Verdict {
+        match self {
+            Policy::Count { text, min, max } => {
+                let text = text.get(rule);
+                let occurrences = pass.matches(&text).count();
+                let (min, max) = (
+                    min.as_ref().map(|min| min.get(rule)),
+                    max.as_ref().map(|max| max.get(rule)),
+                );
+                let allowed = match (min, max) {
+                    (Some(min), Some(max)) => format!("{}-{}", min, max),
+                    (Some(min), None) => format!("at least {}", min),
+                    (None, Some(max)) => format!("at most {}", max),
+                    (None, None) => "any number".to_string(),
+                };
+                Verdict {
+                    valid: min.is_none_or(|min| occurrences >= min)
+                        && max.is_none_or(|max| occurrences <= max),
+                    reason: format!(
+                        "'{}' occurs {}, allowed {}",
+                        text,
+                        match occurrences {
+                            1 => "once".to_string(),
+                            count => format!("{} times", count),
+                        },
+                        allowed
+                    ),
+                }
+            }
+            Policy::At { position, text } => {
+                let (position, text) = (position.get(rule), text.get(rule));
+                let start = position
+                    .checked_sub(1)
+                    .and_then(|idx| pass.char_indices().nth(idx));
+                match start {
+                    Some((start, _)) if pass[start..].starts_with(&text) => Verdict {
+                        valid: true,
+                        reason: format!("position {} matches '{}'", position, text),
+                    },
+                    Some(_) => Verdict {
+                        valid: false,
+                        reason: format!("position {} does not match '{}'", position, text),
+                    },
+                    None => Verdict {
+                        valid: false,
+                        reason: format!("there is no position {}", position),
+                    },
+                }
+            }
+            Policy::Forbid(text) => {
+                let text = text.get(rule);
+                let valid = !pass.contains(&text);
+                Verdict {
+                    valid,
+                    reason: format!("'{}' is {}", text, if valid { "absent" } else { "present" }),
+                }
+            }
+            Policy::Regex(Pattern(regex)) => {
+                let valid = regex.is_match(pass);
+                Verdict {
+                    valid,
+                    reason: format!(
+                        "{} /{}/",
+                        if valid { "matches" } else { "does not match" },
+                        regex
+                    ),
+                }
+            }
+            Policy::And(policies) => {
+                let verdicts = explain_all(policies, rule, pass);
+                let failed: Vec<&Verdict> = verdicts.iter().filter(|v| !v.valid).collect();
+                Verdict {
+                    valid: failed.is_empty(),
+                    reason: if failed.is_empty() {
+                        join_reasons(verdicts.iter())
+                    } else {
+                        join_reasons(failed.into_iter())
+                    },
+                }
+            }
+            Policy::Or(policies) => {
+                let verdicts = explain_all(policies, rule, pass);
+                match verdicts.iter().find(|verdict| verdict.valid) {
+                    Some(verdict) => verdict.clone(),
+                    None => Verdict {
+                        valid: false,
+                        reason: join_reasons(verdicts.iter()),
+                    },
+                }
+            }
+            Policy::Xor(policies) => {
+                let verdicts = explain_all(policies, rule, pass);
+                let held: Vec<&Verdict> = verdicts.iter().filter(|v| v.valid).collect();
+                Verdict {
+                    valid: held.len() % 2 == 1,
+                    reason: match held.len() {
+                        0 => format!(
+                            "none of {} holds: {}",
+                            verdicts.len(),
+                            join_reasons(verdicts.iter())
+                        ),
+                        count if count == verdicts.len() && count == 2 => {
+                            format!("both hold: {}", join_reasons(held.into_iter()))
+                        }
+                        count => format!(
+                            "{} of {} hold: {}",
+                            count,
+                            verdicts.len(),
+                            join_reasons(held.into_iter())
+                        ),
+                    },
+                }
+            }
+            Policy::Not(policy) => {
+                let verdict = policy.explain(rule, pass);
+                Verdict {
+                    valid: !verdict.valid,
+                    reason: format!("not: {}", verdict.reason),
+                }
+            }
+        }
+    }
+}
+
+/// Whether a password follows a policy, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub valid: bool,
+    pub reason: String,
+}
+
+fn explain_all(policies: &[Policy], rule: &PasswordRule, pass: &str) -> Vec<Verdict> {
+    policies
+        .iter()
+        .map(|policy| policy.explain(rule, pass))
+        .collect()
+}
+
+fn join_reasons<'a, I: Iterator<Item = &'a Verdict>>(verdicts: I) -> String {
+    verdicts
+        .map(|verdict| verdict.reason.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The verdicts of every policy on one line of the input, or why the line
+/// could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditLine {
+    Checked {
+        line: usize,
+        text: String,
+        verdicts: Vec<(String, Verdict)>,
+    },
+    Rejected(ParseError),
+}
+
+/// Checks every line of `input` against `policies`. Unlike `Day02::parse`,
+/// lines that cannot be parsed are reported without stopping the audit.
+pub fn audit(input: &str, policies: &Policies) -> Vec<AuditLine> {
+    lines(input)
+        .map(|(line_no, line)| match parse_line(line) {
+            Ok((rule, pass)) => AuditLine::Checked {
+                line: line_no,
+                text: line.to_string(),
+                verdicts: policies
+                    .iter()
+                    .map(|(name, policy)| (name.clone(), policy.explain(&rule, &pass)))
+                    .collect(),
+            },
+            Err(err) => AuditLine::Rejected(err.at_line(line_no)),
+        })
+        .collect()
+}
+
+/// Where the policies of the puzzle are read from, relative to the working
+/// directory like the inputs. Without this file, the copy built into the
+/// binary is used.
+pub static POLICIES_PATH: &str = "policies/d02.json";
+
+/// Reads the policies of the puzzle, `part1` and `part2`, from
+/// `POLICIES_PATH`.
+pub fn puzzle_policies() -> Result<Policies, String> {
+    match fs::read_to_string(POLICIES_PATH) {
+        Ok(json) => parse_policies(&json).map_err(|err| format!("{}: {}", POLICIES_PATH, err)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            parse_policies(include_str!("../../policies/d02.json"))
+        }
+        Err(err) => Err(format!("Could not read {}: {}", POLICIES_PATH, err)),
+    }
+}
+
+/// The puzzle policy of a part, logging why there is none.
+fn puzzle_policy<'a>(input: &'a Passwords, name: &str) -> Option<&'a Policy> {
+    match &input.policies {
+        Ok(policies) => {
+            let policy = policies.get(name);
+            if policy.is_none() {
+                error!("{} has no {} policy", POLICIES_PATH, name);
+            }
+            policy
+        }
+        Err(err) => {
+            error!("{}", err);
+            None
+        }
+    }
+}
+
+/// Named policies, as read from a policy file.
+pub type Policies = BTreeMap<String, Policy>;
+
+/// Parses a policy file: a JSON object of named policies.
+pub fn parse_policies(json: &str) -> Result<Policies, String> {
+    serde_json::from_str(json).map_err(|err| format!("Invalid policies: {}", err))
+}
+
+/// The passwords of an input, with the puzzle policies read along with it so
+/// that a reloaded input also reloads them.
+#[derive(Debug)]
+pub struct Passwords {
+    lines: Vec<(PasswordRule, String)>,
+    policies: Result<Policies, String>,
+}
+
+fn count_valid(input: &Passwords, policy: &Policy) -> usize {
+    input
+        .lines
+        .iter()
+        .filter(|(rule, pass)| policy.check(rule, pass))
+        .count()
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    const QUERIES: &'static [Query] = &[Query {
+        name: "policies",
+        args: "<policy file>",
+        help: "the number of valid passwords for each policy of a JSON file",
+    }];
+    type Input = Passwords;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Passwords {
+            lines: parse_lines(input, parse_line)?,
+            policies: puzzle_policies(),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(count_valid(input, puzzle_policy(input, "part1")?))
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        Some(count_valid(input, puzzle_policy(input, "part2")?))
+    }
+
+    fn query(input: &Self::Input, name: &str, arg: &str) -> Option<Result<String, String>> {
+        match name {
+            "policies" => Some(
+                fs::read_to_string(arg)
+                    .map_err(|err| format!("Could not read {}: {}", arg, err))
+                    .and_then(|json| parse_policies(&json))
+                    .map(|policies| {
+                        policies
+                            .iter()
+                            .map(|(name, policy)| {
+                                format!("{}: {}", name, count_valid(input, policy))
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    }),
+            ),
+            _ => None,
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<(PasswordRule, String), ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^(\\d+)-(\\d+)\\s+(\\w):\\s+(\\w+)\\s*$").unwrap();
+    }
+    let cap = RE
+        .captures(line)
+        .ok_or_else(|| ParseError::new(1, "'<min>-<max> <letter>: <password>'", line))?;
+    let min = parse_bound(&cap, 1)?;
+    let max = parse_bound(&cap, 2)?;
+    let letter = cap[3].chars().next().unwrap();
+    let password_rule = PasswordRule { min, max, letter };
+    Ok((password_rule, cap[4].to_string()))
+}
+
+// Both bounds are also used as 1-based positions, so 0 is rejected.
+fn parse_bound(cap: &Captures, idx: usize) -> Result<usize, ParseError> {
+    let column = cap.get(idx).unwrap().start() + 1;
+    match parse_number::<usize>(&cap[idx], column)? {
+        0 => Err(ParseError::new(column, "a number of at least 1", &cap[idx])),
+        bound => Ok(bound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day02::parse(include_str!("../../examples/example02.txt")).unwrap();
+        assert_eq!(Day02::part1(&input), Some(2));
+        assert_eq!(Day02::part2(&input), Some(1));
+    }
+
+    #[test]
+    fn test_policies() {
+        let policies = parse_policies(
+            r#"{
+                "forbid": {"forbid": "cd"},
+                "regex": {"regex": "^[a-e]+$"},
+                "at": {"at": {"position": 4, "text": "de"}},
+                "count": {"count": {"text": "c", "min": 2}},
+                "combined": {"and": [
+                    {"not": {"count": {"text": "$letter", "max": "$min"}}},
+                    {"or": [{"at": {"position": "$max", "text": "$letter"}}, {"forbid": "$letter"}]}
+                ]},
+                "xor": {"xor": [{"forbid": "a"}, {"forbid": "c"}, {"forbid": "z"}]}
+            }"#,
+        )
+        .unwrap();
+        let input = Day02::parse(include_str!("../../examples/example02.txt")).unwrap();
+        let valid = |name: &str| -> Vec<bool> {
+            input
+                .lines
+                .iter()
+                .map(|(rule, pass)| policies[name].check(rule, pass))
+                .collect()
+        };
+        assert_eq!(valid("forbid"), vec![false, false, true]);
+        assert_eq!(valid("regex"), vec![true, false, true]);
+        assert_eq!(valid("at"), vec![true, false, false]);
+        assert_eq!(valid("count"), vec![false, false, true]);
+        assert_eq!(valid("combined"), vec![false, false, true]);
+        assert_eq!(valid("xor"), vec![true, false, false]);
+
+        for name in policies.keys() {
+            let explained: Vec<bool> = input
+                .lines
+                .iter()
+                .map(|(rule, pass)| policies[name].explain(rule, pass).valid)
+                .collect();
+            assert_eq!(explained, valid(name), "{}", name);
+        }
+
+        assert!(parse_policies(r#"{"p": {"count": {"text": "$foo"}}}"#).is_err());
+        assert!(parse_policies(r#"{"p": {"count": {"text": "a", "min": "$foo"}}}"#).is_err());
+        assert!(parse_policies(r#"{"p": {"regex": "("}}"#).is_err());
+        assert!(parse_policies(r#"{"p": {"nand": []}}"#).is_err());
+    }
+
+    #[test]
+    fn test_audit() {
+        let audited = audit(
+            "1-3 a: abcde\n\n1-3 a: aaaaa\n1-3 b\n",
+            &puzzle_policies().unwrap(),
+        );
+        let checked = |line: usize, text: &str, verdicts: [(bool, &str); 2]| AuditLine::Checked {
+            line,
+            text: text.to_string(),
+            verdicts: ["part1", "part2"]
+                .iter()
+                .zip(verdicts.iter())
+                .map(|(name, &(valid, reason))| {
+                    let reason = reason.to_string();
+                    (name.to_string(), Verdict { valid, reason })
+                })
+                .collect(),
+        };
+        assert_eq!(
+            audited,
+            vec![
+                checked(
+                    1,
+                    "1-3 a: abcde",
+                    [
+                        (true, "'a' occurs once, allowed 1-3"),
+                        (true, "1 of 2 hold: position 1 matches 'a'"),
+                    ]
+                ),
+                checked(
+                    3,
+                    "1-3 a: aaaaa",
+                    [
+                        (false, "'a' occurs 5 times, allowed 1-3"),
+                        (
+                            false,
+                            "both hold: position 1 matches 'a', position 3 matches 'a'"
+                        ),
+                    ]
+                ),
+                AuditLine::Rejected(
+                    ParseError::new(1, "'<min>-<max> <letter>: <password>'", "1-3 b").at_line(4)
+                ),
+            ]
+        );
+
+        let policies = parse_policies(r#"{"no-letter": {"not": {"forbid": "$letter"}}}"#).unwrap();
+        assert_eq!(
+            audit("1-3 z: abcde\n", &policies),
+            vec![AuditLine::Checked {
+                line: 1,
+                text: "1-3 z: abcde".to_string(),
+                verdicts: vec![(
+                    "no-letter".to_string(),
+                    Verdict {
+                        valid: false,
+                        reason: "not: 'z' is absent".to_string(),
+                    }
+                )],
+            }]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::grid::Grid;
+use crate::{ParseError, Solver};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TileType {
+    Open,
+    Tree,
+}
+
+impl TileType {
+    fn new(v: char) -> Option<TileType> {
+        match v {
+            '.' => Some(TileType::Open),
+            '#' => Some(TileType::Tree),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TileType::Open => write!(f, "."),
+            TileType::Tree => write!(f, "#"),
+        }
+    }
+}
+
+const SLOPES: [(usize, usize); 5] = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    type Input = Grid<TileType>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "'.' or '#'", TileType::new)
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(tree_encountered(input, 1, 3))
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        Some(
+            SLOPES
+                .iter()
+                .map(|&(down_shift, right_shift)| tree_encountered(input, down_shift, right_shift))
+                .product(),
+        )
+    }
+}
+
+fn tree_encountered(slope: &Grid<TileType>, down_shift: usize, right_shift: usize) -> usize {
+    (0..slope.height())
+        .step_by(down_shift)
+        .enumerate()
+        .filter(|&(step, y)| *slope.get_wrapped(step * right_shift, y) == TileType::Tree)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day03::parse(include_str!("../../examples/example03.txt")).unwrap();
+        assert_eq!(Day03::part1(&input), Some(7));
+        assert_eq!(Day03::part2(&input), Some(336));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::parse::{column_of, records};
+use crate::{ParseError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Passport {
+    byr: Option<String>,
+    iyr: Option<String>,
+    eyr: Option<String>,
+    hgt: Option<String>,
+    hcl: Option<String>,
+    ecl: Option<String>,
+    pid: Option<String>,
+    #[allow(dead_code)] // Country ID is optional for both policies
+    cid: Option<String>,
+}
+
+lazy_static! {
+    static ref HCL_REGEX: Regex = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
+    static ref PID_REGEX: Regex = Regex::new(r"^\d{9}$").unwrap();
+}
+
+const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+impl Passport {
+    /// Parses one blank-line separated record, as grouped by `parse::records`
+    /// or `stream::records`.
+    pub fn new(record: &[(usize, &str)]) -> Result<Self, ParseError> {
+        let mut hm: HashMap<&str, String> = HashMap::new();
+        for (line_no, line) in record {
+            for field in line.split(' ').filter(|s| !s.is_empty()) {
+                let column = column_of(line, field);
+                let kv: Vec<&str> = field.split(':').collect();
+                if kv.len() != 2 {
+                    return Err(ParseError::new(column, "'<key>:<value>'", field).at_line(*line_no));
+                }
+                if !FIELDS.contains(&kv[0]) {
+                    let expected = format!("one of {}", FIELDS.join(", "));
+                    return Err(ParseError::new(column, expected, kv[0]).at_line(*line_no));
+                }
+                hm.insert(kv[0], kv[1].to_string());
+            }
+        }
+        Ok(Self {
+            byr: hm.get("byr").cloned(),
+            iyr: hm.get("iyr").cloned(),
+            eyr: hm.get("eyr").cloned(),
+            hgt: hm.get("hgt").cloned(),
+            hcl: hm.get("hcl").cloned(),
+            ecl: hm.get("ecl").cloned(),
+            pid: hm.get("pid").cloned(),
+            cid: hm.get("cid").cloned(),
+        })
+    }
+    fn is_valid(&self) -> bool {
+        self.byr.is_some()
+            && self.iyr.is_some()
+            && self.eyr.is_some()
+            && self.hgt.is_some()
+            && self.hcl.is_some()
+            && self.ecl.is_some()
+            && self.pid.is_some()
+    }
+
+    fn is_valid_strict(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        let byr = self.byr.as_ref().unwrap();
+        if byr.len() != 4 || !between(&byr.parse::<u32>().ok(), &Some(1920), &Some(2002)) {
+            return false;
+        }
+        let iyr = self.iyr.as_ref().unwrap();
+        if iyr.len() != 4 || !between(&iyr.parse::<u32>().ok(), &Some(2010), &Some(2020)) {
+            return false;
+        }
+        let eyr = self.eyr.as_ref().unwrap();
+        if eyr.len() != 4 || !between(&eyr.parse::<u32>().ok(), &Some(2020), &Some(2030)) {
+            return false;
+        }
+        let hgt = self.hgt.as_ref().unwrap();
+        if hgt.ends_with("cm") && hgt.len() == 5 {
+            if !between(&hgt[0..3].parse::<u32>().ok(), &Some(150), &Some(193)) {
+                return false;
+            }
+        } else if hgt.ends_with("in") && hgt.len() == 4 {
+            if !between(&hgt[0..2].parse::<u32>().ok(), &Some(59), &Some(76)) {
+                return false;
+            }
+        } else {
+            return false;
+        }
+        if !HCL_REGEX.is_match(self.hcl.as_ref().unwrap().as_str()) {
+            return false;
+        }
+        if !["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            .contains(&self.ecl.as_ref().unwrap().as_str())
+        {
+            return false;
+        }
+        if !PID_REGEX.is_match(self.pid.as_ref().unwrap().as_str()) {
+            return false;
+        }
+        true
+    }
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Passport>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        records(input)
+            .iter()
+            .map(|record| Passport::new(record))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(input.iter().filter(|x| x.is_valid()).count())
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        Some(input.iter().filter(|x| x.is_valid_strict()).count())
+    }
+}
+
+/// Counts the passports valid for each part as they are read, holding only
+/// one at a time.
+pub fn stream<E, I>(passports: I) -> Result<(usize, usize), E>
+where
+    I: IntoIterator<Item = Result<Passport, E>>,
+{
+    let (mut valid, mut strict) = (0, 0);
+    for passport in passports {
+        let passport = passport?;
+        valid += usize::from(passport.is_valid());
+        strict += usize::from(passport.is_valid_strict());
+    }
+    Ok((valid, strict))
+}
+
+fn between<T: PartialOrd>(value: &T, min: &T, max: &T) -> bool {
+    value >= min && value <= max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day04::parse(include_str!("../../examples/example04.txt")).unwrap();
+        assert_eq!(Day04::part1(&input), Some(2));
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = Day04::parse(include_str!("../../examples/example04-2.txt")).unwrap();
+        assert_eq!(Day04::part2(&input), Some(4));
+    }
+}
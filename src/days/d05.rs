@@ -0,0 +1,203 @@
+use crate::parse::parse_lines;
+use crate::{ParseError, Query, Solver};
+
+#[derive(Debug)]
+struct Interval {
+    min: u16,
+    max: u16,
+}
+
+impl Interval {
+    fn keep_upper_half(&mut self) {
+        self.min += self.len() / 2;
+    }
+
+    fn keep_lower_half(&mut self) {
+        self.max -= self.len() / 2
+    }
+
+    fn len(&self) -> u16 {
+        self.max - self.min + 1
+    }
+
+    fn get_single_value(&self) -> Option<u16> {
+        if self.len() == 1 {
+            Some(self.min)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Seat {
+    row: u16,
+    column: u16,
+}
+
+impl Seat {
+    fn new(input: &str) -> Option<Self> {
+        if input.is_empty() {
+            return None;
+        }
+        let mut row_interval = Interval { min: 0, max: 127 };
+        let mut column_interval = Interval { min: 0, max: 7 };
+        for letter in input.chars() {
+            match letter {
+                'F' => {
+                    row_interval.keep_lower_half();
+                }
+                'B' => {
+                    row_interval.keep_upper_half();
+                }
+                'L' => {
+                    column_interval.keep_lower_half();
+                }
+                'R' => {
+                    column_interval.keep_upper_half();
+                }
+                _ => {}
+            }
+        }
+        if let (Some(row), Some(col)) = (
+            row_interval.get_single_value(),
+            column_interval.get_single_value(),
+        ) {
+            return Some(Seat { row, column: col });
+        }
+        None
+    }
+
+    fn compute_set_id(&self) -> u16 {
+        self.row * 8 + self.column
+    }
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "ids",
+            args: "",
+            help: "the sorted seat IDs",
+        },
+        Query {
+            name: "seat",
+            args: "<boarding pass>",
+            help: "the row, column and ID of a boarding pass",
+        },
+    ];
+    type Input = Vec<Seat>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| parse_seat(line.trim()))
+    }
+
+    fn part1(input: &Self::Input) -> Option<u16> {
+        input.iter().map(Seat::compute_set_id).max()
+    }
+
+    fn part2(input: &Self::Input) -> Option<u16> {
+        let seats_number: Vec<u16> = input.iter().map(Seat::compute_set_id).collect();
+        find_missing_seat(&seats_number)
+    }
+
+    fn query(input: &Self::Input, name: &str, arg: &str) -> Option<Result<String, String>> {
+        match name {
+            "ids" => {
+                let mut ids: Vec<u16> = input.iter().map(Seat::compute_set_id).collect();
+                ids.sort_unstable();
+                Some(Ok(format!("{:?}", ids)))
+            }
+            "seat" => Some(
+                parse_seat(arg)
+                    .map(|seat| {
+                        format!(
+                            "row {}, column {}, ID {}",
+                            seat.row,
+                            seat.column,
+                            seat.compute_set_id()
+                        )
+                    })
+                    .map_err(|err| err.to_string()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+fn parse_seat(line: &str) -> Result<Seat, ParseError> {
+    for (idx, letter) in line.chars().enumerate() {
+        let (allowed, expected) = match idx {
+            0..=6 => ("FB", "'F' or 'B'"),
+            7..=9 => ("LR", "'L' or 'R'"),
+            _ => ("", "end of line"),
+        };
+        if !allowed.contains(letter) {
+            return Err(ParseError::new(idx + 1, expected, letter.to_string()));
+        }
+    }
+    Seat::new(line).ok_or_else(|| ParseError::new(line.len() + 1, "a 10 letter seat", line))
+}
+
+fn find_missing_seat(seats: &[u16]) -> Option<u16> {
+    let mut sorted_seats = seats.to_vec();
+    sorted_seats.sort_unstable();
+    for (index, value) in sorted_seats.iter().enumerate() {
+        if let Some(next_value) = sorted_seats.get(index + 1) {
+            if *next_value != (value + 1) {
+                return Some(value + 1);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day05;
+    use crate::Solver;
+
+    #[test]
+    fn test_seat_new() {
+        use super::Seat;
+        assert_eq!(Seat::new(""), None);
+        assert_eq!(Seat::new("BFFFBBFRRR"), Some(Seat { row: 70, column: 7 }));
+        assert_eq!(Seat::new("FFFBBBFRRR"), Some(Seat { row: 14, column: 7 }));
+        assert_eq!(
+            Seat::new("BBFFBBFRLL"),
+            Some(Seat {
+                row: 102,
+                column: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day05::parse(include_str!("../../examples/example05.txt")).unwrap();
+        assert_eq!(Day05::part1(&input), Some(820));
+    }
+
+    #[test]
+    fn test_query() {
+        let input = Day05::parse("BFFFBBFRRR\nFFFBBBFRRR\n").unwrap();
+        assert_eq!(
+            Day05::query(&input, "ids", ""),
+            Some(Ok("[119, 567]".to_string()))
+        );
+        assert_eq!(
+            Day05::query(&input, "seat", "BBFFBBFRLL"),
+            Some(Ok("row 102, column 4, ID 820".to_string()))
+        );
+        assert!(matches!(
+            Day05::query(&input, "seat", "BBFFBBF"),
+            Some(Err(_))
+        ));
+        assert_eq!(Day05::query(&input, "row", ""), None);
+    }
+}
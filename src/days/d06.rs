@@ -0,0 +1,97 @@
+use crate::parse::records;
+use crate::{ParseError, Solver};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<Vec<String>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        records(input)
+            .iter()
+            .map(|record| parse_group(record))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(input.iter().map(|vs| anyone(vs)).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        Some(input.iter().map(|vs| everyone(vs)).sum())
+    }
+}
+
+/// Parses the answers of one group, a blank-line separated record as grouped
+/// by `parse::records` or `stream::records`.
+pub fn parse_group(record: &[(usize, &str)]) -> Result<Vec<String>, ParseError> {
+    record
+        .iter()
+        .map(|&(line_no, line)| parse_answers(line).map_err(|err| err.at_line(line_no)))
+        .collect()
+}
+
+/// Sums the answers of both parts as the groups are read, holding only one
+/// group at a time.
+pub fn stream<E, I>(groups: I) -> Result<(usize, usize), E>
+where
+    I: IntoIterator<Item = Result<Vec<String>, E>>,
+{
+    let (mut one, mut two) = (0, 0);
+    for group in groups {
+        let group = group?;
+        one += anyone(&group);
+        two += everyone(&group);
+    }
+    Ok((one, two))
+}
+
+/// The number of questions anyone in the group answered yes to.
+fn anyone(group: &[String]) -> usize {
+    group.join("").chars().collect::<HashSet<char>>().len()
+}
+
+/// The number of questions everyone in the group answered yes to.
+fn everyone(group: &[String]) -> usize {
+    let vhs: Vec<HashSet<char>> = group
+        .iter()
+        .map(|s| s.chars().collect::<HashSet<char>>())
+        .collect();
+    intersection(&vhs).map_or(0, |hs| hs.len())
+}
+
+fn parse_answers(line: &str) -> Result<String, ParseError> {
+    match line.chars().position(|c| !c.is_ascii_lowercase()) {
+        Some(idx) => Err(ParseError::new(
+            idx + 1,
+            "a question letter 'a'-'z'",
+            line[idx..].chars().next().unwrap().to_string(),
+        )),
+        None => Ok(line.to_string()),
+    }
+}
+
+fn intersection(vhs: &[HashSet<char>]) -> Option<HashSet<char>> {
+    if vhs.is_empty() {
+        return None;
+    }
+    Some(vhs.iter().fold(vhs[0].clone(), |acc, hs| {
+        hs.intersection(&acc).copied().collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day06::parse(include_str!("../../examples/example06.txt")).unwrap();
+        assert_eq!(Day06::part1(&input), Some(11));
+        assert_eq!(Day06::part2(&input), Some(6));
+    }
+}
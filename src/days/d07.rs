@@ -0,0 +1,336 @@
+use crate::parse::{column_of, lines, parse_number};
+use crate::{ParseError, Query, Solver, Variant};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, PartialEq)]
+pub struct BagRule {
+    name: String,
+    contained: HashMap<String, u32>,
+}
+
+type BagMap = HashMap<String, HashMap<String, u32>>;
+
+static TARGET: &str = "shiny gold bags";
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    const VARIANTS1: &'static [Variant<BagMap, usize>] = &[("reverse-bfs", |map| {
+        Some(count_containing_bags(map, TARGET))
+    })];
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "contains",
+            args: "<color>",
+            help: "the bags that eventually contain a <color> bag",
+        },
+        Query {
+            name: "inside",
+            args: "<color>",
+            help: "the number of bags inside a <color> bag",
+        },
+    ];
+    type Input = BagMap;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rules = lines(input)
+            .map(|(line_no, line)| {
+                parse_line(line)
+                    .map(|rule| (line_no, line, rule))
+                    .map_err(|err| err.at_line(line_no))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let map: BagMap = rules
+            .iter()
+            .map(|(_, _, rule)| (rule.name.clone(), rule.contained.clone()))
+            .collect();
+        // The searches rely on every bag having a rule, and on no bag
+        // containing itself.
+        for (line_no, line, rule) in &rules {
+            if let Some(bag) = rule.contained.keys().find(|bag| !map.contains_key(*bag)) {
+                let color = bag.trim_end_matches(" bag");
+                let contents = line.find("contain").unwrap_or(0);
+                let column = line[contents..]
+                    .find(color)
+                    .map_or(1, |idx| contents + idx + 1);
+                return Err(
+                    ParseError::new(column, "a bag having a rule", bag.as_str()).at_line(*line_no)
+                );
+            }
+        }
+        if let Some(bag) = find_cycle(&map) {
+            let (line_no, line, _) = rules.iter().find(|(_, _, rule)| rule.name == bag).unwrap();
+            return Err(
+                ParseError::new(1, "a bag not containing itself, directly or not", *line)
+                    .at_line(*line_no),
+            );
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Option<usize> {
+        Some(find_all_containing_bags(map, TARGET).len())
+    }
+
+    fn part2(map: &Self::Input) -> Option<u64> {
+        find_nb_of_bags_contained(map, TARGET)
+    }
+
+    fn query(map: &Self::Input, name: &str, arg: &str) -> Option<Result<String, String>> {
+        let query: fn(&BagMap, &str) -> String = match name {
+            "contains" => |map, bag| {
+                let mut containing: Vec<String> =
+                    find_all_containing_bags(map, bag).into_iter().collect();
+                containing.sort();
+                format!("{} bags: {}", containing.len(), containing.join(", "))
+            },
+            "inside" => |map, bag| {
+                find_nb_of_bags_contained(map, bag).map_or_else(
+                    || "too many to count".to_string(),
+                    |count| count.to_string(),
+                )
+            },
+            _ => return None,
+        };
+        let color = arg.trim();
+        let color = color
+            .strip_suffix(" bags")
+            .or_else(|| color.strip_suffix(" bag"))
+            .unwrap_or(color);
+        let bag = format!("{} bag", color);
+        if !map.contains_key(&bag) {
+            return Some(Err(format!("No rule for {}s", bag)));
+        }
+        Some(Ok(query(map, &bag)))
+    }
+}
+
+fn parse_line(line: &str) -> Result<BagRule, ParseError> {
+    let splited = line.split("contain").map(str::trim).collect::<Vec<&str>>();
+    if splited.len() != 2 || splited[0].is_empty() {
+        return Err(ParseError::new(
+            1,
+            "'<color> bags contain <contents>.'",
+            line,
+        ));
+    }
+    let name = splited[0].replace("bags", "bag");
+    if splited[1].starts_with("no other bag") {
+        return Ok(BagRule {
+            name,
+            contained: HashMap::new(),
+        });
+    }
+    let contained_bags: Vec<(String, u32)> = splited[1]
+        .split(',')
+        .map(str::trim)
+        .map(|s| s.trim_end_matches('.'))
+        .map(|s| {
+            let column = column_of(line, s);
+            let vs = s.splitn(2, ' ').collect::<Vec<&str>>();
+            if vs.len() != 2 {
+                return Err(ParseError::new(column, "'<count> <color> bag'", s));
+            }
+            Ok((vs[1].replace("bags", "bag"), parse_number(vs[0], column)?))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(BagRule {
+        name,
+        contained: contained_bags.into_iter().collect(),
+    })
+}
+
+fn find_all_containing_bags(map: &BagMap, name: &str) -> HashSet<String> {
+    let sanitized_name = name.replace("bags", "bag");
+    let mut ret = HashSet::new();
+    for (bag, contained) in map {
+        if contained.contains_key(&sanitized_name) {
+            ret.insert(bag.clone());
+            ret.extend(find_all_containing_bags(map, bag));
+        }
+    }
+    ret
+}
+
+/// Same as `find_all_containing_bags(..).len()`, but walking the rules
+/// backwards once instead of once per path.
+fn count_containing_bags(map: &BagMap, name: &str) -> usize {
+    let mut containers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (bag, contained) in map {
+        for inner in contained.keys() {
+            containers.entry(inner).or_default().push(bag);
+        }
+    }
+    let sanitized_name = name.replace("bags", "bag");
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::from(vec![sanitized_name.as_str()]);
+    while let Some(bag) = queue.pop_front() {
+        for &container in containers.get(bag).into_iter().flatten() {
+            if seen.insert(container) {
+                queue.push_back(container);
+            }
+        }
+    }
+    seen.len()
+}
+
+/// The number of bags inside a `name` bag, `None` if there is no rule for it
+/// or the number overflows.
+fn find_nb_of_bags_contained(map: &BagMap, name: &str) -> Option<u64> {
+    map.get(&name.replace("bags", "bag"))?
+        .iter()
+        .try_fold(0u64, |total, (bag, contained)| {
+            let inside = find_nb_of_bags_contained(map, bag)?.checked_add(1)?;
+            total.checked_add(u64::from(*contained).checked_mul(inside)?)
+        })
+}
+
+/// A bag eventually containing itself, if any. Every contained bag must have
+/// a rule.
+fn find_cycle(map: &BagMap) -> Option<&str> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        InProgress,
+        Done,
+    }
+    let contents = |bag: &str| -> Vec<&str> { map[bag].keys().map(String::as_str).collect() };
+    let mut marks: HashMap<&str, Mark> = HashMap::new();
+    let mut roots: Vec<&str> = map.keys().map(String::as_str).collect();
+    roots.sort_unstable();
+    for root in roots {
+        if marks.contains_key(root) {
+            continue;
+        }
+        marks.insert(root, Mark::InProgress);
+        // Iterative, as chains of bags can be longer than the stack allows.
+        let mut stack = vec![(root, contents(root))];
+        while let Some((bag, inner)) = stack.last_mut() {
+            let bag = *bag;
+            match inner.pop() {
+                Some(next) => match marks.get(next) {
+                    Some(Mark::InProgress) => return Some(next),
+                    Some(Mark::Done) => {}
+                    None => {
+                        marks.insert(next, Mark::InProgress);
+                        stack.push((next, contents(next)));
+                    }
+                },
+                None => {
+                    marks.insert(bag, Mark::Done);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day07;
+    use crate::Solver;
+
+    #[test]
+    fn test_parse_line_empty() {
+        use super::parse_line;
+        assert!(parse_line("").is_err());
+    }
+    #[test]
+    fn test_parse_line_0_bag() {
+        use super::{parse_line, BagRule};
+        use std::collections::HashMap;
+        assert_eq!(
+            parse_line("faded blue bags contain no other bags."),
+            Ok(BagRule {
+                name: "faded blue bag".to_string(),
+                contained: HashMap::new(),
+            })
+        );
+    }
+    #[test]
+    fn test_parse_line_1_bag() {
+        use super::{parse_line, BagRule};
+        assert_eq!(
+            parse_line("bright white bags contain 1 shiny gold bag."),
+            Ok(BagRule {
+                name: "bright white bag".to_string(),
+                contained: vec![("shiny gold bag".to_string(), 1)]
+                    .into_iter()
+                    .collect(),
+            })
+        );
+    }
+    #[test]
+    fn test_parse_line_2_bag() {
+        use super::{parse_line, BagRule};
+        assert_eq!(
+            parse_line("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            Ok(BagRule {
+                name: "light red bag".to_string(),
+                contained: vec![
+                    ("bright white bag".to_string(), 1),
+                    ("muted yellow bag".to_string(), 2)
+                ]
+                .into_iter()
+                .collect(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day07::parse(include_str!("../../examples/example07.txt")).unwrap();
+        assert_eq!(Day07::part1(&input), Some(4));
+        assert_eq!(Day07::part2(&input), Some(32));
+    }
+
+    #[test]
+    fn test_query() {
+        let input = Day07::parse(include_str!("../../examples/example07.txt")).unwrap();
+        assert_eq!(
+            Day07::query(&input, "contains", "shiny gold"),
+            Some(Ok(
+                "4 bags: bright white bag, dark orange bag, light red bag, muted yellow bag"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            Day07::query(&input, "inside", "shiny gold bags"),
+            Some(Ok("32".to_string()))
+        );
+        assert!(matches!(
+            Day07::query(&input, "inside", "plaid"),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let err = Day07::parse("shiny gold bags contain 1 red bag.\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (1, 27, "red bag")
+        );
+
+        let err = Day07::parse(
+            "shiny gold bags contain 1 red bag.\n\
+             red bags contain 2 blue bags.\n\
+             blue bags contain 1 red bag.\n",
+        )
+        .unwrap_err();
+        assert!(err.line == 2 || err.line == 3, "{}", err);
+
+        let input = Day07::parse("red bags contain no other bags.\n").unwrap();
+        assert_eq!(Day07::part1(&input), Some(0));
+        assert_eq!(Day07::part2(&input), None);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = Day07::parse(include_str!("../../examples/example07-2.txt")).unwrap();
+        assert_eq!(Day07::part2(&input), Some(126));
+    }
+}
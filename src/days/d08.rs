@@ -0,0 +1,246 @@
+use crate::parse::{column_of, parse_lines, parse_number};
+use crate::{ParseError, Query, Solver};
+use log::{debug, trace};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+#[derive(Debug)]
+struct ProcessorState {
+    addr: usize,
+    acc: i32,
+}
+
+/// Why a program stopped before its end.
+#[derive(Debug)]
+enum Halt {
+    /// About to execute an instruction a second time.
+    Loop(ProcessorState),
+    /// The instruction at the state's address jumps before the start of the
+    /// program or overflows the accumulator.
+    Fault(ProcessorState),
+}
+
+impl Instruction {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let splitted: Vec<_> = input.split(' ').map(str::trim).collect();
+        if splitted.len() != 2 {
+            return Err(ParseError::new(1, "'<operation> <argument>'", input));
+        }
+        let arg = parse_number(splitted[1], column_of(input, splitted[1]))?;
+        match splitted[0] {
+            "nop" => Ok(Instruction::Nop(arg)),
+            "acc" => Ok(Instruction::Acc(arg)),
+            "jmp" => Ok(Instruction::Jmp(arg)),
+            op => Err(ParseError::new(1, "one of nop, acc, jmp", op)),
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "run",
+            args: "",
+            help: "runs the program until it ends or loops",
+        },
+        Query {
+            name: "run-patched",
+            args: "<address>",
+            help: "runs the program with the jmp or nop at <address> swapped",
+        },
+    ];
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::new)
+    }
+
+    fn part1(listing: &Self::Input) -> Option<i32> {
+        part1(listing)
+    }
+
+    fn part2(listing: &Self::Input) -> Option<i32> {
+        part2(listing)
+    }
+
+    fn query(listing: &Self::Input, name: &str, arg: &str) -> Option<Result<String, String>> {
+        let listing = match name {
+            "run" => listing.to_vec(),
+            "run-patched" => match arg.trim().parse::<usize>() {
+                Ok(addr) if addr < listing.len() => {
+                    if let Instruction::Acc(_) = listing[addr] {
+                        return Some(Err(format!("Instruction {} is an acc", addr)));
+                    }
+                    generate_modified_listing(listing, addr)
+                }
+                _ => {
+                    return Some(Err(format!(
+                        "Expected an address below {}, found '{}'",
+                        listing.len(),
+                        arg.trim()
+                    )))
+                }
+            },
+            _ => return None,
+        };
+        Some(Ok(match trace(&listing) {
+            Ok(acc) => format!("terminated with acc {}", acc),
+            Err(Halt::Loop(state)) => {
+                format!("looped at address {} with acc {}", state.addr, state.acc)
+            }
+            Err(Halt::Fault(state)) => {
+                format!("faulted at address {} with acc {}", state.addr, state.acc)
+            }
+        }))
+    }
+}
+
+fn add(addr: usize, offset: i32) -> Option<usize> {
+    let distance = usize::try_from(offset.unsigned_abs()).ok()?;
+    if offset >= 0 {
+        addr.checked_add(distance)
+    } else {
+        addr.checked_sub(distance)
+    }
+}
+
+fn swap_jmp_nop(instruction: &mut Instruction) {
+    match instruction {
+        Instruction::Nop(offset) => *instruction = Instruction::Jmp(*offset),
+        Instruction::Jmp(offset) => *instruction = Instruction::Nop(*offset),
+        Instruction::Acc(_) => {}
+    }
+}
+
+fn generate_modified_listing(listing: &[Instruction], idx: usize) -> Vec<Instruction> {
+    let mut ret = listing.to_vec();
+    swap_jmp_nop(ret.get_mut(idx).unwrap());
+    ret
+}
+
+/// The state after executing `instr`, `None` if it faults.
+fn process(instr: &Instruction, state: &ProcessorState) -> Option<ProcessorState> {
+    let (next_addr, next_acc) = match instr {
+        Instruction::Nop(_) => (state.addr + 1, state.acc),
+        Instruction::Acc(acc) => (state.addr + 1, state.acc.checked_add(*acc)?),
+        Instruction::Jmp(offset) => (add(state.addr, *offset)?, state.acc),
+    };
+    trace!(
+        "{:>4}: {:?}, next {} with acc {}",
+        state.addr,
+        instr,
+        next_addr,
+        next_acc
+    );
+    Some(ProcessorState {
+        addr: next_addr,
+        acc: next_acc,
+    })
+}
+
+/// Runs the program to its end, returning the final accumulator, or why it
+/// stopped before.
+fn trace(listing: &[Instruction]) -> Result<i32, Halt> {
+    let mut visited: HashSet<usize> = HashSet::new();
+
+    let mut state = ProcessorState { addr: 0, acc: 0 };
+    while let Some(instr) = listing.get(state.addr) {
+        if visited.contains(&state.addr) {
+            return Err(Halt::Loop(state));
+        }
+        visited.insert(state.addr);
+        state = match process(instr, &state) {
+            Some(next_state) => next_state,
+            None => return Err(Halt::Fault(state)),
+        };
+    }
+    Ok(state.acc)
+}
+
+fn run(listing: &[Instruction]) -> Option<i32> {
+    trace(listing).ok()
+}
+
+/// The accumulator before an instruction runs a second time, `None` if the
+/// program ends or faults first.
+fn part1(listing: &[Instruction]) -> Option<i32> {
+    match trace(listing) {
+        Err(Halt::Loop(state)) => Some(state.acc),
+        Ok(_) | Err(Halt::Fault(_)) => None,
+    }
+}
+
+fn part2(listing: &[Instruction]) -> Option<i32> {
+    // Swapping an acc changes nothing, so only jmp and nop are worth a run.
+    (0..listing.len())
+        .filter(|&idx| !matches!(listing[idx], Instruction::Acc(_)))
+        .find_map(|idx| {
+            debug!("swapping instruction {}: {:?}", idx, listing[idx]);
+            run(&generate_modified_listing(listing, idx))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day08::parse(include_str!("../../examples/example08.txt")).unwrap();
+        assert_eq!(Day08::part1(&input), Some(5));
+        assert_eq!(Day08::part2(&input), Some(8));
+    }
+
+    #[test]
+    fn test_no_loop() {
+        for program in &["nop +0\n", "", "jmp -1\n", "acc +2147483647\nacc +1\n"] {
+            let input = Day08::parse(program).unwrap();
+            assert_eq!(Day08::part1(&input), None, "{:?}", program);
+        }
+        let input = Day08::parse("jmp -1\nacc +3\n").unwrap();
+        assert_eq!(Day08::part2(&input), Some(3));
+    }
+
+    #[test]
+    fn test_query() {
+        let input = Day08::parse(include_str!("../../examples/example08.txt")).unwrap();
+        assert_eq!(
+            Day08::query(&input, "run", ""),
+            Some(Ok("looped at address 1 with acc 5".to_string()))
+        );
+        assert_eq!(
+            Day08::query(&input, "run-patched", "7"),
+            Some(Ok("terminated with acc 8".to_string()))
+        );
+        assert!(matches!(
+            Day08::query(&input, "run-patched", "1"),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            Day08::query(&input, "run-patched", "9"),
+            Some(Err(_))
+        ));
+
+        let input = Day08::parse("nop -1\nacc +1\n").unwrap();
+        assert_eq!(
+            Day08::query(&input, "run", ""),
+            Some(Ok("terminated with acc 1".to_string()))
+        );
+        assert_eq!(
+            Day08::query(&input, "run-patched", "0"),
+            Some(Ok("faulted at address 0 with acc 0".to_string()))
+        );
+    }
+}
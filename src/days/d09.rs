@@ -0,0 +1,178 @@
+use crate::parse::{parse_lines, parse_number};
+use crate::{ParseError, Solver, Variant};
+use std::collections::{HashSet, VecDeque};
+
+pub const PREAMBLE_LEN: usize = 25;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    const VARIANTS1: &'static [Variant<Vec<u64>, u64>] = &[("stream", |numbers| {
+        stream_part1(numbers.iter().map(|&n| Ok::<_, ()>(n)), PREAMBLE_LEN).ok()?
+    })];
+    const VARIANTS2: &'static [Variant<Vec<u64>, u64>] = &[("two-pointer", |numbers| {
+        part2_two_pointer(numbers, part1(numbers, PREAMBLE_LEN)?)
+    })];
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| parse_number(line, 1))
+    }
+
+    fn part1(input: &Self::Input) -> Option<u64> {
+        part1(input, PREAMBLE_LEN)
+    }
+
+    fn part2(input: &Self::Input) -> Option<u64> {
+        part2(input, part1(input, PREAMBLE_LEN)?)
+    }
+}
+
+fn generate_all_sums(preamble: &[u64]) -> HashSet<u64> {
+    let mut ret = HashSet::new();
+    for (idx1, value1) in preamble.iter().enumerate() {
+        for (idx2, value2) in preamble.iter().enumerate() {
+            // A sum beyond u64 cannot be any number of the input.
+            if let Some(sum) = value1.checked_add(*value2).filter(|_| idx1 != idx2) {
+                ret.insert(sum);
+            }
+        }
+    }
+    ret
+}
+
+fn part1(input: &[u64], preamble_len: usize) -> Option<u64> {
+    for (idx, value) in input.iter().skip(preamble_len).enumerate() {
+        if !generate_all_sums(&input[idx..idx + preamble_len]).contains(value) {
+            return Some(*value);
+        }
+    }
+    None
+}
+
+/// The sum of the smallest and largest numbers of `range`, `None` if it
+/// overflows.
+fn weakness(range: &[u64]) -> Option<u64> {
+    range.iter().min()?.checked_add(*range.iter().max()?)
+}
+
+fn part2(input: &[u64], part1: u64) -> Option<u64> {
+    for set_size in 2..input.len().saturating_sub(2) {
+        for idx in 0..input.len() - set_size {
+            let slice = &input[idx..idx + set_size];
+            if slice
+                .iter()
+                .try_fold(0u64, |sum, value| sum.checked_add(*value))
+                == Some(part1)
+            {
+                return weakness(slice);
+            }
+        }
+    }
+    None
+}
+
+fn part2_two_pointer(input: &[u64], part1: u64) -> Option<u64> {
+    // At most `part1` plus one number, which a u128 always holds.
+    let (mut start, mut sum) = (0, 0u128);
+    for (end, value) in input.iter().enumerate() {
+        sum += u128::from(*value);
+        while sum > u128::from(part1) {
+            sum -= u128::from(input[start]);
+            start += 1;
+        }
+        if sum == u128::from(part1) && end > start {
+            return weakness(&input[start..=end]);
+        }
+    }
+    None
+}
+
+/// Streaming part 1, keeping only the last `preamble_len` numbers. Reading
+/// stops at the first number breaking the rule.
+pub fn stream_part1<E, I>(numbers: I, preamble_len: usize) -> Result<Option<u64>, E>
+where
+    I: IntoIterator<Item = Result<u64, E>>,
+{
+    let mut window: VecDeque<u64> = VecDeque::with_capacity(preamble_len + 1);
+    for number in numbers {
+        let value = number?;
+        if window.len() == preamble_len {
+            let valid = window.iter().enumerate().any(|(idx, first)| {
+                window
+                    .iter()
+                    .skip(idx + 1)
+                    .any(|second| first.checked_add(*second) == Some(value))
+            });
+            if !valid {
+                return Ok(Some(value));
+            }
+            window.pop_front();
+        }
+        window.push_back(value);
+    }
+    Ok(None)
+}
+
+/// Streaming part 2, keeping only the contiguous range whose sum does not
+/// exceed `target`. Reading stops once the range is found.
+pub fn stream_part2<E, I>(numbers: I, target: u64) -> Result<Option<u64>, E>
+where
+    I: IntoIterator<Item = Result<u64, E>>,
+{
+    let mut window: VecDeque<u64> = VecDeque::new();
+    // The window sums to at most `target` before each number, so this fits.
+    let mut sum = 0u128;
+    for number in numbers {
+        let value = number?;
+        window.push_back(value);
+        sum += u128::from(value);
+        while sum > u128::from(target) {
+            sum -= u128::from(window.pop_front().unwrap());
+        }
+        if sum == u128::from(target) && window.len() >= 2 {
+            return Ok(weakness(window.make_contiguous()));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day09::parse(include_str!("../../examples/example09.txt")).unwrap();
+        assert_eq!(part1(&input, 5), Some(127));
+        assert_eq!(part2(&input, 127), Some(62));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut numbers = vec![u64::MAX; 26];
+        assert_eq!(part1(&numbers, PREAMBLE_LEN), Some(u64::MAX));
+        assert_eq!(part2(&numbers, u64::MAX), None);
+        numbers[25] = 1;
+        assert_eq!(part1(&numbers, PREAMBLE_LEN), Some(1));
+        assert_eq!(
+            part2_two_pointer(&[u64::MAX, 1, u64::MAX - 1], u64::MAX),
+            Some(u64::MAX)
+        );
+        assert_eq!(part2_two_pointer(&[u64::MAX, 1, 2], 3), Some(3));
+    }
+
+    #[test]
+    fn test_stream() {
+        let numbers = || input_numbers().into_iter().map(Ok::<_, ()>);
+        assert_eq!(stream_part1(numbers(), 5), Ok(Some(127)));
+        assert_eq!(stream_part2(numbers(), 127), Ok(Some(62)));
+    }
+
+    fn input_numbers() -> Vec<u64> {
+        Day09::parse(include_str!("../../examples/example09.txt")).unwrap()
+    }
+}
@@ -0,0 +1,199 @@
+use crate::parse::{parse_lines, parse_number};
+use crate::{ParseError, Solver, Variant};
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    const VARIANTS1: &'static [Variant<Vec<u64>, usize>] = &[("bitset", |adapters| {
+        Some(stream(streamable(adapters)?).ok()?.0)
+    })];
+    const VARIANTS2: &'static [Variant<Vec<u64>, u64>] =
+        &[("bitset", |adapters| stream(streamable(adapters)?).ok()?.1)];
+    type Input = Vec<u64>;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| parse_number(line, 1))
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<u64> {
+        part2(input)
+    }
+}
+
+/// The highest joltage `stream` takes, bounding its bitset to 128 MiB. Parsed
+/// inputs have no such bound.
+pub const MAX_JOLTAGE: u64 = 1 << 30;
+
+/// Parses the joltage of an adapter for `stream`, at most `MAX_JOLTAGE`.
+pub fn parse_joltage(line: &str) -> Result<u64, ParseError> {
+    match parse_number(line, 1)? {
+        joltage if joltage <= MAX_JOLTAGE => Ok(joltage),
+        _ => Err(ParseError::new(
+            1,
+            format!("a joltage of at most {}", MAX_JOLTAGE),
+            line,
+        )),
+    }
+}
+
+fn part1(input: &[u64]) -> usize {
+    let mut adapters = input.to_vec();
+    adapters.sort_unstable();
+    let mut differences: Vec<u64> = Vec::new();
+    let mut current = 0;
+    for adapter in adapters {
+        let delta = adapter - current;
+        differences.push(delta);
+        current += delta;
+    }
+    differences.iter().filter(|v| **v == 1).count()
+        * (differences.iter().filter(|v| **v == 3).count() + 1)
+}
+
+fn explore_paths_brute_force(
+    adapters: &[u64],
+    joltage: u64,
+    cache: &mut HashMap<u64, Option<u64>>,
+    max_joltage: u64,
+) -> Option<u64> {
+    let mut ret = 0u64;
+    if joltage == max_joltage {
+        ret = 1
+    } else {
+        for (idx, adapter) in adapters.iter().enumerate() {
+            if *adapter > joltage && *adapter <= joltage.saturating_add(3) {
+                if !cache.contains_key(adapter) {
+                    let found = explore_paths_brute_force(
+                        &adapters[idx + 1..],
+                        *adapter,
+                        cache,
+                        max_joltage,
+                    );
+                    cache.insert(*adapter, found);
+                }
+                ret = ret.checked_add(cache[adapter]?)?;
+            } else {
+                break;
+            }
+        }
+    }
+    Some(ret)
+}
+
+/// The number of arrangements, `None` when it overflows.
+fn part2(input: &[u64]) -> Option<u64> {
+    let mut adapters = input.to_vec();
+    adapters.sort_unstable();
+    let max_joltage = *adapters.iter().max()?;
+    let mut cache: HashMap<u64, Option<u64>> = HashMap::new();
+    explore_paths_brute_force(&adapters[..], 0, &mut cache, max_joltage)
+}
+
+/// The adapters as `stream` takes them, `None` when a joltage is beyond its
+/// bitset.
+fn streamable(adapters: &[u64]) -> Option<impl Iterator<Item = Result<u64, ()>> + '_> {
+    if adapters.iter().any(|&joltage| joltage > MAX_JOLTAGE) {
+        return None;
+    }
+    Some(adapters.iter().map(|&joltage| Ok(joltage)))
+}
+
+/// Solves both parts while streaming `numbers`, storing the adapters as a
+/// bitset: memory is bounded by the highest joltage rather than the number of
+/// adapters. Joltages must be at most `MAX_JOLTAGE`, as `parse_joltage`
+/// ensures. Part 2 is `None` when the number of arrangements overflows.
+pub fn stream<E, I>(numbers: I) -> Result<(usize, Option<u64>), E>
+where
+    I: IntoIterator<Item = Result<u64, E>>,
+{
+    let mut adapters: Vec<u64> = Vec::new();
+    for number in numbers {
+        let value = number?;
+        debug_assert!(value <= MAX_JOLTAGE);
+        let word = usize::try_from(value / 64).unwrap();
+        if word >= adapters.len() {
+            adapters.resize(word + 1, 0);
+        }
+        adapters[word] |= 1 << (value % 64);
+    }
+    let (mut ones, mut threes) = (0, 1);
+    // The last three joltages reached, with the number of ways to reach them.
+    let mut reached: VecDeque<(u64, Option<u64>)> = VecDeque::from(vec![(0, Some(1))]);
+    for (word_idx, &word) in adapters.iter().enumerate() {
+        let mut bits = word;
+        while bits != 0 {
+            let joltage = word_idx as u64 * 64 + u64::from(bits.trailing_zeros());
+            bits &= bits - 1;
+            let previous = reached.back().unwrap().0;
+            match joltage - previous {
+                1 => ones += 1,
+                3 => threes += 1,
+                _ => {}
+            }
+            let ways = reached
+                .iter()
+                .filter(|(other, _)| joltage - other <= 3)
+                .try_fold(0u64, |sum, (_, ways)| sum.checked_add((*ways)?));
+            reached.push_back((joltage, ways));
+            if reached.len() > 3 {
+                reached.pop_front();
+            }
+        }
+    }
+    let arrangements = match reached.back() {
+        Some((0, _)) => None,
+        Some((_, ways)) => *ways,
+        None => None,
+    };
+    Ok((ones * threes, arrangements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day10::parse(include_str!("../../examples/example10.txt")).unwrap();
+        assert_eq!(Day10::part1(&input), Some(35));
+        assert_eq!(Day10::part2(&input), Some(8));
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = Day10::parse(include_str!("../../examples/example10-2.txt")).unwrap();
+        assert_eq!(Day10::part1(&input), Some(220));
+        assert_eq!(Day10::part2(&input), Some(19208));
+    }
+
+    #[test]
+    fn test_max_joltage() {
+        assert_eq!(parse_joltage("1073741824"), Ok(MAX_JOLTAGE));
+        assert!(parse_joltage("1073741825").is_err());
+        let input = Day10::parse("1\n18446744073709551615\n").unwrap();
+        assert_eq!(Day10::part1(&input), Some(1));
+        assert_eq!(Day10::VARIANTS1[0].1(&input), None);
+    }
+
+    #[test]
+    fn test_stream() {
+        for example in &[
+            include_str!("../../examples/example10.txt"),
+            include_str!("../../examples/example10-2.txt"),
+        ] {
+            let input = Day10::parse(example).unwrap();
+            let (one, two) = stream(input.iter().map(|&n| Ok::<_, ()>(n))).unwrap();
+            assert_eq!(Some(one), Day10::part1(&input));
+            assert_eq!(two, Day10::part2(&input));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::grid::{Grid, DIRECTIONS8};
+use crate::{ParseError, Solver};
+use log::debug;
+use std::fmt;
+
+type CountFn = fn(input: &Grid<Tile>, x: usize, y: usize) -> usize;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tile {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl Tile {
+    fn new(v: char) -> Option<Self> {
+        match v {
+            '.' => Some(Tile::Floor),
+            'L' => Some(Tile::Empty),
+            '#' => Some(Tile::Occupied),
+            _ => None,
+        }
+    }
+
+    fn is_occupied(&self) -> bool {
+        *self == Tile::Occupied
+    }
+
+    fn switch(&mut self) {
+        match self {
+            Tile::Floor => {}
+            Tile::Empty => *self = Tile::Occupied,
+            Tile::Occupied => *self = Tile::Empty,
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tile::Floor => write!(f, "."),
+            Tile::Empty => write!(f, "L"),
+            Tile::Occupied => write!(f, "#"),
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Input = Grid<Tile>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "'.', 'L' or '#'", Tile::new)
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        Some(stabilize(input, count_adjacent_occupied, 4))
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        Some(stabilize(input, count_adjacent_visible, 5))
+    }
+}
+
+fn process(input: &Grid<Tile>, count_fn: CountFn, max_occupied: usize) -> Vec<(usize, usize)> {
+    input
+        .positions()
+        .filter(|&(x, y)| match input[(x, y)] {
+            Tile::Floor => false,
+            Tile::Empty => count_fn(input, x, y) == 0,
+            Tile::Occupied => count_fn(input, x, y) >= max_occupied,
+        })
+        .collect()
+}
+
+fn count_adjacent_occupied(input: &Grid<Tile>, x: usize, y: usize) -> usize {
+    input
+        .neighbours8(x, y)
+        .filter(|&pos| input[pos].is_occupied())
+        .count()
+}
+
+fn count_adjacent_visible(input: &Grid<Tile>, x: usize, y: usize) -> usize {
+    DIRECTIONS8
+        .iter()
+        .filter(|&&(dx, dy)| is_occupied_in_direction(input, x, y, dx, dy))
+        .count()
+}
+
+fn is_occupied_in_direction(input: &Grid<Tile>, x: usize, y: usize, dx: isize, dy: isize) -> bool {
+    input
+        .cast(x, y, dx, dy)
+        .map(|pos| &input[pos])
+        .find(|tile| **tile != Tile::Floor)
+        .is_some_and(Tile::is_occupied)
+}
+
+fn modify(input: &Grid<Tile>, to_modify: &[(usize, usize)]) -> Grid<Tile> {
+    let mut ret = input.clone();
+    for &tile in to_modify {
+        ret[tile].switch();
+    }
+    ret
+}
+
+/// Applies the seating rules until nothing changes and counts the occupied
+/// seats.
+fn stabilize(input: &Grid<Tile>, count_fn: CountFn, max_occupied: usize) -> usize {
+    let mut modified = input.clone();
+    let mut to_modify = process(&modified, count_fn, max_occupied);
+    let mut generation = 1;
+    while !to_modify.is_empty() {
+        debug!(
+            "generation {}: {} seats changed",
+            generation,
+            to_modify.len()
+        );
+        modified = modify(&modified, &to_modify);
+        to_modify = process(&modified, count_fn, max_occupied);
+        generation += 1;
+    }
+    debug!("generation {}: stable", generation);
+    modified.cells().filter(|t| t.is_occupied()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day11::parse(include_str!("../../examples/example11.txt")).unwrap();
+        assert_eq!(Day11::part1(&input), Some(37));
+        assert_eq!(Day11::part2(&input), Some(26));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::parse::{parse_lines, parse_number};
+use crate::{ParseError, Solver};
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    North(i32),
+    South(i32),
+    East(i32),
+    West(i32),
+    Left(i32),
+    Right(i32),
+    Fwd(i32),
+}
+
+impl Instruction {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let mut chars = s.chars();
+        let ins = chars
+            .next()
+            .ok_or_else(|| ParseError::new(1, "an instruction", s))?;
+        let instruction: fn(i32) -> Self = match ins {
+            'N' => Instruction::North,
+            'S' => Instruction::South,
+            'E' => Instruction::East,
+            'W' => Instruction::West,
+            'L' => Instruction::Left,
+            'R' => Instruction::Right,
+            'F' => Instruction::Fwd,
+            _ => {
+                let expected = "one of N, S, E, W, L, R, F";
+                return Err(ParseError::new(1, expected, ins.to_string()));
+            }
+        };
+        let val = parse_number::<i32>(chars.as_str(), 2)?;
+        if (ins == 'L' || ins == 'R') && ![0, 90, 180, 270].contains(&val) {
+            return Err(ParseError::new(
+                2,
+                "an angle of 0, 90, 180 or 270",
+                chars.as_str(),
+            ));
+        }
+        Ok(instruction(val))
+    }
+}
+
+#[derive(Debug)]
+struct Ship {
+    position: (i32, i32),
+    angle: i32,
+}
+
+#[derive(Debug)]
+struct WaypointShip {
+    ship: Ship,
+    waypoint: (i32, i32),
+}
+
+/// Moves `position` by `offset` units along each axis, `None` if it leaves
+/// the i32 range.
+fn moved(position: (i32, i32), offset: (i32, i32), units: i32) -> Option<(i32, i32)> {
+    Some((
+        position.0.checked_add(offset.0.checked_mul(units)?)?,
+        position.1.checked_add(offset.1.checked_mul(units)?)?,
+    ))
+}
+
+impl Ship {
+    /// Follows `instr`, `None` if the ship leaves the i32 range.
+    fn apply(&mut self, instr: &Instruction) -> Option<()> {
+        match instr {
+            Instruction::North(unit) => self.position = moved(self.position, (0, 1), *unit)?,
+            Instruction::South(unit) => self.position = moved(self.position, (0, -1), *unit)?,
+            Instruction::East(unit) => self.position = moved(self.position, (1, 0), *unit)?,
+            Instruction::West(unit) => self.position = moved(self.position, (-1, 0), *unit)?,
+            Instruction::Left(angle) => self.angle = (self.angle - angle + 360) % 360,
+            Instruction::Right(angle) => self.angle = (self.angle + angle) % 360,
+            Instruction::Fwd(unit) => match self.angle {
+                0 => {
+                    self.apply(&Instruction::North(*unit))?;
+                }
+                90 => {
+                    self.apply(&Instruction::East(*unit))?;
+                }
+                180 => {
+                    self.apply(&Instruction::South(*unit))?;
+                }
+                270 => {
+                    self.apply(&Instruction::West(*unit))?;
+                }
+                _ => {
+                    panic!("Unexpected ship angle: {}", self.angle)
+                }
+            },
+        }
+        Some(())
+    }
+
+    fn distance(&self) -> Option<i32> {
+        self.position
+            .0
+            .checked_abs()?
+            .checked_add(self.position.1.checked_abs()?)
+    }
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self {
+            position: (0, 0),
+            angle: 90,
+        }
+    }
+}
+
+impl Default for WaypointShip {
+    fn default() -> Self {
+        Self {
+            ship: Ship::default(),
+            waypoint: (10, 1),
+        }
+    }
+}
+
+impl WaypointShip {
+    /// Follows `instr`, `None` if the ship or its waypoint leaves the i32
+    /// range.
+    fn apply(&mut self, instr: &Instruction) -> Option<()> {
+        let (x, y) = self.waypoint;
+        match instr {
+            Instruction::North(unit) => self.waypoint = moved(self.waypoint, (0, 1), *unit)?,
+            Instruction::South(unit) => self.waypoint = moved(self.waypoint, (0, -1), *unit)?,
+            Instruction::East(unit) => self.waypoint = moved(self.waypoint, (1, 0), *unit)?,
+            Instruction::West(unit) => self.waypoint = moved(self.waypoint, (-1, 0), *unit)?,
+            Instruction::Left(angle) => {
+                self.waypoint = match angle {
+                    0 => self.waypoint,
+                    90 => (y.checked_neg()?, x),
+                    180 => (x.checked_neg()?, y.checked_neg()?),
+                    270 => (y, x.checked_neg()?),
+                    _ => panic!("Invalid left angle {}", angle),
+                }
+            }
+            Instruction::Right(angle) => {
+                self.waypoint = match angle {
+                    0 => self.waypoint,
+                    90 => (y, x.checked_neg()?),
+                    180 => (x.checked_neg()?, y.checked_neg()?),
+                    270 => (y.checked_neg()?, x),
+                    _ => panic!("Invalid right angle {}", angle),
+                }
+            }
+            Instruction::Fwd(unit) => {
+                self.ship.position = moved(self.ship.position, self.waypoint, *unit)?;
+            }
+        }
+        Some(())
+    }
+
+    fn distance(&self) -> Option<i32> {
+        self.ship.distance()
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::new)
+    }
+
+    fn part1(input: &Self::Input) -> Option<i32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<i32> {
+        part2(input)
+    }
+}
+
+/// The distance travelled, `None` when it leaves the i32 range.
+fn part1(input: &[Instruction]) -> Option<i32> {
+    let mut ship = Ship::default();
+    input.iter().try_for_each(|instr| ship.apply(instr))?;
+    ship.distance()
+}
+
+/// The distance travelled with a waypoint, `None` when it leaves the i32
+/// range.
+fn part2(input: &[Instruction]) -> Option<i32> {
+    let mut ship = WaypointShip::default();
+    input.iter().try_for_each(|instr| ship.apply(instr))?;
+    ship.distance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day12::parse(include_str!("../../examples/example12.txt")).unwrap();
+        assert_eq!(Day12::part1(&input), Some(25));
+        assert_eq!(Day12::part2(&input), Some(286));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day12::parse("F2147483647\nF2147483647\n").unwrap();
+        assert_eq!(Day12::part1(&input), None);
+        assert_eq!(Day12::part2(&input), None);
+        let input = Day12::parse("F2147483647\nL180\nF2147483647\n").unwrap();
+        assert_eq!(Day12::part1(&input), Some(0));
+    }
+}
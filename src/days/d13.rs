@@ -0,0 +1,142 @@
+use crate::parse::{column_of, lines, parse_number};
+use crate::{ParseError, Solver};
+use log::debug;
+
+#[derive(Debug)]
+pub struct Notes {
+    timestamp: usize,
+    buses: Vec<(usize, usize)>,
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Input = Notes;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let (line_no, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, "a timestamp", "").at_line(1))?;
+        let timestamp = parse_number(line, 1).map_err(|err| err.at_line(line_no))?;
+        let (line_no, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, "a bus list", "").at_line(line_no + 1))?;
+        let buses = parse_buses(line).map_err(|err| err.at_line(line_no))?;
+        Ok(Notes { timestamp, buses })
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        part2(&input.buses)
+    }
+}
+
+fn parse_buses(line: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut buses = Vec::new();
+    for (i, s) in line.split(',').enumerate() {
+        if s == "x" {
+            continue;
+        }
+        let column = column_of(line, s);
+        match parse_number::<usize>(s, column) {
+            Ok(v) if v > 0 => buses.push((i, v)),
+            _ => return Err(ParseError::new(column, "a bus id or 'x'", s)),
+        }
+    }
+    if buses.is_empty() {
+        return Err(ParseError::new(1, "at least one bus id", line));
+    }
+    Ok(buses)
+}
+
+/// The wait for the earliest bus times its ID, `None` if it overflows.
+fn part1(input: &Notes) -> Option<usize> {
+    let ts = input.timestamp;
+    input
+        .buses
+        .iter()
+        .map(|&(_, bus)| ((bus - ts % bus) % bus, bus))
+        .min_by_key(|&(wait, _)| wait)
+        .and_then(|(wait, bus)| wait.checked_mul(bus))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The earliest timestamp at which each bus departs at its offset, `None`
+/// when no timestamp meets every offset or it overflows. Once a bus is
+/// matched, stepping by the least common multiple of the buses matched so
+/// far keeps them matched.
+fn part2(input: &[(usize, usize)]) -> Option<usize> {
+    let mut ts: usize = 0;
+    let mut step: usize = 1;
+    for &(idx, bus) in input {
+        // The timestamps reached modulo `bus` repeat after this many steps.
+        let mut tries = bus / gcd(step, bus);
+        while ts.checked_add(idx)? % bus != 0 {
+            tries -= 1;
+            if tries == 0 {
+                debug!("bus {} can never depart at offset {}", bus, idx);
+                return None;
+            }
+            debug!(
+                "t = {} misses bus {} at offset {}, next t = {}",
+                ts,
+                bus,
+                idx,
+                ts.saturating_add(step)
+            );
+            ts = ts.checked_add(step)?;
+        }
+        step = (step / gcd(step, bus)).checked_mul(bus)?;
+    }
+    // Timestamp 0 is before the earliest one.
+    Some(if ts == 0 { step } else { ts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day13::parse(include_str!("../../examples/example13.txt")).unwrap();
+        assert_eq!(Day13::part1(&input), Some(295));
+        assert_eq!(Day13::part2(&input), Some(1068781));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = Day13::parse("18446744073709551615\n7,13\n").unwrap();
+        assert_eq!(Day13::part1(&input), Some(6 * 7));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_buses("17,x,13,19").unwrap()), Some(3417));
+        assert_eq!(part2(&parse_buses("67,7,59,61").unwrap()), Some(754018));
+        assert_eq!(part2(&parse_buses("67,x,7,59,61").unwrap()), Some(779210));
+        assert_eq!(part2(&parse_buses("67,7,x,59,61").unwrap()), Some(1261476));
+        assert_eq!(
+            part2(&parse_buses("1789,37,47,1889").unwrap()),
+            Some(1202161486)
+        );
+        assert_eq!(part2(&parse_buses("2,x,4").unwrap()), Some(2));
+        assert_eq!(part2(&parse_buses("6,x,x,x,4").unwrap()), Some(12));
+        assert_eq!(part2(&parse_buses("4,x,6").unwrap()), Some(4));
+        assert_eq!(part2(&parse_buses("2,4").unwrap()), None);
+        assert_eq!(part2(&parse_buses("6,x,9").unwrap()), None);
+    }
+}
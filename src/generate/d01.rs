@@ -0,0 +1,66 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+use std::collections::HashSet;
+
+const TARGET: i32 = 2020;
+
+/// Counts the pairs and triples of entries, by position, summing to `TARGET`.
+fn sums(entries: &[i32]) -> (usize, usize) {
+    let mut pairs = 0;
+    let mut triples = 0;
+    for i in 0..entries.len() {
+        for j in i + 1..entries.len() {
+            if entries[i] + entries[j] == TARGET {
+                pairs += 1;
+            }
+            for k in j + 1..entries.len() {
+                if entries[i] + entries[j] + entries[k] == TARGET {
+                    triples += 1;
+                }
+            }
+        }
+    }
+    (pairs, triples)
+}
+
+/// An expense report of `size` entries with exactly one pair and one triple
+/// summing to 2020.
+///
+/// The planted entries are drawn until they hold no other such sum. The other
+/// entries are above 1010, so that no two of them can be part of a sum, and
+/// avoid the values completing one with the planted entries.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (pair, triple) = loop {
+        let first = rng.gen_range(1..1010);
+        let pair = [first, TARGET - first];
+        let first = rng.gen_range(1..673);
+        let second = rng.gen_range(1..673);
+        let triple = [first, second, TARGET - first - second];
+        let planted: Vec<i32> = pair.iter().chain(&triple).copied().collect();
+        if sums(&planted) == (1, 1) {
+            break (pair, triple);
+        }
+    };
+    let mut entries: Vec<i32> = pair.iter().chain(&triple).copied().collect();
+    let mut forbidden = HashSet::new();
+    for (idx, first) in entries.iter().enumerate() {
+        forbidden.insert(TARGET - first);
+        for second in &entries[idx + 1..] {
+            forbidden.insert(TARGET - first - second);
+        }
+    }
+    while entries.len() < size {
+        let entry = rng.gen_range(1011..TARGET);
+        if !forbidden.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    entries.shuffle(rng);
+
+    Generated {
+        input: entries.iter().map(|entry| format!("{}\n", entry)).collect(),
+        part1: (pair[0] * pair[1]).into(),
+        part2: triple.iter().product::<i32>().into(),
+    }
+}
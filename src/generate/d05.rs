@@ -0,0 +1,37 @@
+use super::{Generated, Rng};
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+const SEATS: u16 = 128 * 8;
+
+fn boarding_pass(id: u16) -> String {
+    let row = (0..7)
+        .rev()
+        .map(|bit| if id >> (bit + 3) & 1 == 1 { 'B' } else { 'F' });
+    let column = (0..3)
+        .rev()
+        .map(|bit| if id >> bit & 1 == 1 { 'R' } else { 'L' });
+    row.chain(column).collect()
+}
+
+/// The boarding passes of a full flight of `size` passengers: contiguous seat
+/// IDs, except for the missing one of our seat. The plane bounds `size` to
+/// 1021.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.clamp(2, usize::from(SEATS) - 3) as u16;
+    // One more ID than passengers, as ours is missing.
+    let first = rng.gen_range(1..SEATS - size - 1);
+    let last = first + size;
+    let ours = rng.gen_range(first + 1..last);
+    let mut passes: Vec<String> = (first..=last)
+        .filter(|&id| id != ours)
+        .map(boarding_pass)
+        .collect();
+    passes.shuffle(rng);
+
+    Generated {
+        input: passes.iter().map(|pass| format!("{}\n", pass)).collect(),
+        part1: last.into(),
+        part2: ours.into(),
+    }
+}
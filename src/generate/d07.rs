@@ -0,0 +1,131 @@
+use super::{Generated, Rng};
+use rand::seq::{index, SliceRandom};
+use rand::Rng as _;
+
+static ADJECTIVES: &[&str] = &[
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+
+static COLORS: &[&str] = &[
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+static TARGET: &str = "shiny gold";
+
+/// At most this many bags are below the shiny gold one, which keeps the number
+/// of bags it holds within a `u64`.
+const MAX_INSIDE: usize = 24;
+
+fn color(idx: usize) -> String {
+    let adjective = ADJECTIVES[idx % ADJECTIVES.len()];
+    let color = COLORS[idx / ADJECTIVES.len() % COLORS.len()];
+    match idx / (ADJECTIVES.len() * COLORS.len()) {
+        0 => format!("{} {}", adjective, color),
+        round => format!("{} {} {}", adjective, color, round),
+    }
+}
+
+/// `size` acyclic bag rules: a bag only contains bags of a higher index. The
+/// shiny gold bag comes close to the end, so that the bags it holds are few
+/// while any number of bags may hold it.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(2);
+    let mut names: Vec<String> = (0..size).map(color).collect();
+    let target = size.saturating_sub(MAX_INSIDE + 1);
+    let target_name = names.iter().position(|name| name == TARGET);
+    match target_name {
+        Some(idx) => names.swap(idx, target),
+        None => names[target] = TARGET.to_string(),
+    }
+
+    let mut contents: Vec<Vec<(usize, u64)>> = Vec::with_capacity(size);
+    for idx in 0..size {
+        let (max_kinds, max_count) = if idx < target { (4, 5) } else { (2, 2) };
+        // The shiny gold bag always holds some bags, when any come after it.
+        let min_kinds = usize::from(idx == target && idx + 1 < size);
+        let kinds = rng.gen_range(min_kinds..=max_kinds.min(size - idx - 1));
+        contents.push(
+            index::sample(rng, size - idx - 1, kinds)
+                .iter()
+                .map(|offset| (idx + 1 + offset, rng.gen_range(1..=max_count)))
+                .collect(),
+        );
+    }
+
+    // Bags inside the bags from the shiny gold one, computed from the last one
+    // since bags only hold bags after them.
+    let mut inside = vec![0u64; size];
+    for idx in (target..size).rev() {
+        inside[idx] = contents[idx]
+            .iter()
+            .map(|&(other, count)| count * (1 + inside[other]))
+            .sum();
+    }
+    // Whether each bag eventually holds the shiny gold one.
+    let mut holds = vec![false; size];
+    for idx in (0..target).rev() {
+        holds[idx] = contents[idx]
+            .iter()
+            .any(|&(other, _)| other == target || holds[other]);
+    }
+
+    let mut rules: Vec<String> = contents
+        .iter()
+        .enumerate()
+        .map(|(idx, contained)| {
+            let list = if contained.is_empty() {
+                "no other bags".to_string()
+            } else {
+                contained
+                    .iter()
+                    .map(|&(other, count)| {
+                        let plural = if count == 1 { "" } else { "s" };
+                        format!("{} {} bag{}", count, names[other], plural)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            format!("{} bags contain {}.\n", names[idx], list)
+        })
+        .collect();
+    rules.shuffle(rng);
+
+    Generated {
+        input: rules.concat(),
+        part1: holds.iter().filter(|&&holds| holds).count().into(),
+        part2: inside[target].into(),
+    }
+}
@@ -0,0 +1,64 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+/// Boot code of about `size` instructions where swapping exactly one `jmp` or
+/// `nop`, the `jmp` back to the start, lets the program terminate.
+///
+/// The code runs straight from the start to that `jmp`, then after it to the
+/// end. Along the way, `jmp`s skip over traps, `jmp +0` that never run, and
+/// every `nop` points to a trap: swapping any instruction but the corrupted
+/// one either sends the program into a trap or is never executed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(4);
+    let corrupted = rng.gen_range(1..size - 1);
+    let mut code: Vec<Instruction> = Vec::with_capacity(size + 1);
+    let mut traps: Vec<usize> = Vec::new();
+    let (mut before, mut after) = (0, 0);
+    while code.len() < size {
+        let addr = code.len();
+        if addr == corrupted {
+            code.push(Instruction::Jmp(-(addr as i64)));
+            continue;
+        }
+        match rng.gen_range(0..6) {
+            0 if !traps.is_empty() => {
+                let trap = traps[rng.gen_range(0..traps.len())];
+                code.push(Instruction::Nop(trap as i64 - addr as i64));
+            }
+            1 if addr + 2 < size && addr + 1 != corrupted => {
+                code.push(Instruction::Jmp(2));
+                code.push(Instruction::Jmp(0));
+                traps.push(addr + 1);
+            }
+            _ => {
+                let value = rng.gen_range(-99..=99);
+                code.push(Instruction::Acc(value));
+                if addr < corrupted {
+                    before += value;
+                } else {
+                    after += value;
+                }
+            }
+        }
+    }
+
+    Generated {
+        input: code
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Nop(arg) => format!("nop {:+}\n", arg),
+                Instruction::Acc(arg) => format!("acc {:+}\n", arg),
+                Instruction::Jmp(arg) => format!("jmp {:+}\n", arg),
+            })
+            .collect(),
+        part1: before.into(),
+        part2: (before + after).into(),
+    }
+}
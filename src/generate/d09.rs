@@ -0,0 +1,78 @@
+use super::{Generated, Rng};
+use rand::Rng as _;
+
+const PREAMBLE_LEN: usize = 25;
+
+/// Valid numbers grow about exponentially, so only this many come before the
+/// invalid one to stay well within a `u64`.
+const MAX_VALID: usize = 800;
+
+fn is_sum_of_two(window: &[u64], value: u64) -> bool {
+    window.iter().enumerate().any(|(idx, first)| {
+        window[idx + 1..]
+            .iter()
+            .any(|second| first + second == value)
+    })
+}
+
+/// Counts the contiguous ranges of at least two numbers summing to `target`,
+/// all numbers being positive.
+fn ranges_summing_to(numbers: &[u64], target: u64) -> usize {
+    let mut count = 0;
+    let (mut start, mut sum) = (0, 0);
+    for (end, value) in numbers.iter().enumerate() {
+        sum += value;
+        while sum > target {
+            sum -= numbers[start];
+            start += 1;
+        }
+        if sum == target && end > start {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// About `size` numbers after a 25 number preamble: valid numbers, each the sum
+/// of two of the 25 before, then the invalid one, the sum of a planted range
+/// of earlier numbers, then numbers too large to be part of any range.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let valid = size.clamp(PREAMBLE_LEN + 2, MAX_VALID);
+    let (numbers, invalid, weakness) = loop {
+        let mut numbers: Vec<u64> = (0..PREAMBLE_LEN).map(|_| rng.gen_range(1..=50)).collect();
+        while numbers.len() < valid {
+            // Summing two of the smallest numbers slows down the growth.
+            let mut window = numbers[numbers.len() - PREAMBLE_LEN..].to_vec();
+            window.sort_unstable();
+            let first = rng.gen_range(0..6);
+            let second = loop {
+                let second = rng.gen_range(0..6);
+                if second != first {
+                    break second;
+                }
+            };
+            numbers.push(window[first] + window[second]);
+        }
+        let len = rng.gen_range(2..=PREAMBLE_LEN.min(valid / 2));
+        let start = rng.gen_range(0..valid - PREAMBLE_LEN - len + 1);
+        let range = &numbers[start..start + len];
+        let invalid: u64 = range.iter().sum();
+        if !is_sum_of_two(&numbers[valid - PREAMBLE_LEN..], invalid)
+            && ranges_summing_to(&numbers, invalid) == 1
+        {
+            let weakness = range.iter().min().unwrap() + range.iter().max().unwrap();
+            break (numbers, invalid, weakness);
+        }
+    };
+
+    let mut input: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+    input.push_str(&format!("{}\n", invalid));
+    for _ in valid + 1..size {
+        input.push_str(&format!("{}\n", rng.gen_range(invalid + 1..=invalid * 2)));
+    }
+    Generated {
+        input,
+        part1: invalid.into(),
+        part2: weakness.into(),
+    }
+}
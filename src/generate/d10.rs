@@ -0,0 +1,66 @@
+use super::{Generated, Rng};
+use crate::days::d10::MAX_JOLTAGE;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+/// Runs of more than one difference of 1 multiply the arrangements, so there
+/// are at most this many to keep their number within a `u64`.
+const MAX_LONG_RUNS: usize = 20;
+
+/// Differences are at most 3, so this many adapters stay within the joltages
+/// `d10::stream` takes.
+const MAX_SIZE: usize = (MAX_JOLTAGE / 3) as usize;
+
+/// Ways to cross a run of `len` differences of 1 between two adapters that
+/// cannot be skipped.
+fn run_arrangements(len: usize) -> u64 {
+    let mut ways = [1, 1, 2];
+    for _ in 2..len {
+        ways = [ways[1], ways[2], ways.iter().sum()];
+    }
+    ways[len.min(2)]
+}
+
+/// A bag of `size` adapters chaining with differences of 1 and 3 only. Every
+/// adapter around a difference of 3 is needed, so the arrangements are the
+/// product of the ways to cross each run of differences of 1.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.min(MAX_SIZE);
+    let mut differences: Vec<u64> = Vec::with_capacity(size + 4);
+    let mut long_runs = 0;
+    while differences.len() < size {
+        let run = if long_runs < MAX_LONG_RUNS && rng.gen_bool(0.2) {
+            long_runs += 1;
+            rng.gen_range(2..=4)
+        } else {
+            rng.gen_range(0..=1)
+        };
+        differences.extend(std::iter::repeat_n(1, run));
+        differences.push(3);
+    }
+    differences.truncate(size);
+
+    let ones = differences.iter().filter(|&&diff| diff == 1).count();
+    let threes = differences.len() - ones;
+    let arrangements = differences
+        .split(|&diff| diff == 3)
+        .map(|run| run_arrangements(run.len()))
+        .product::<u64>();
+    let mut adapters: Vec<u64> = differences
+        .iter()
+        .scan(0, |joltage, diff| {
+            *joltage += diff;
+            Some(*joltage)
+        })
+        .collect();
+    adapters.shuffle(rng);
+
+    Generated {
+        input: adapters
+            .iter()
+            .map(|adapter| format!("{}\n", adapter))
+            .collect(),
+        part1: (ones * (threes + 1)).into(),
+        part2: arrangements.into(),
+    }
+}
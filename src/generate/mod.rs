@@ -0,0 +1,111 @@
+use crate::Answer;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+pub mod d01;
+pub mod d05;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+
+/// The random number generator of every generator. Its output is stable
+/// across platforms and releases, so a seed always gives the same input.
+pub type Rng = ChaCha8Rng;
+
+/// A random puzzle input with the answers it was built to have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+type Generator = fn(&mut Rng, usize) -> Generated;
+
+static GENERATORS: &[(u8, Generator)] = &[
+    (1, d01::generate),
+    (5, d05::generate),
+    (7, d07::generate),
+    (8, d08::generate),
+    (9, d09::generate),
+    (10, d10::generate),
+];
+
+/// The days having a generator.
+pub fn days() -> impl Iterator<Item = u8> {
+    GENERATORS.iter().map(|&(day, _)| day)
+}
+
+/// Generates an input for `day` from `seed`. `size` is the approximate number
+/// of lines, bounded to what the day's puzzle allows.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    let &(_, generator) = GENERATORS.iter().find(|&&(d, _)| d == day)?;
+    Some(generator(&mut Rng::seed_from_u64(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+    use crate::{registry, Part};
+
+    #[test]
+    fn test_solvers_find_generated_answers() {
+        for day in days() {
+            for seed in 0..4 {
+                let generated = generate(day, seed, 200).unwrap();
+                let parsed = Source::Text(&generated.input)
+                    .load_with(registry::get(day).unwrap())
+                    .unwrap();
+                let answers = (parsed.solve(Part::One), parsed.solve(Part::Two));
+                assert_eq!(
+                    answers,
+                    (Some(generated.part1), Some(generated.part2)),
+                    "day {} seed {}",
+                    day,
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        for day in days() {
+            let runner = registry::get(day).unwrap();
+            for seed in 0..4 {
+                let generated = generate(day, seed, 300).unwrap();
+                let parsed = runner.parse(&generated.input).unwrap();
+                let disagreements = registry::crosscheck(runner, parsed.as_ref());
+                assert!(
+                    disagreements.is_empty(),
+                    "day {} seed {}: {:?}",
+                    day,
+                    seed,
+                    disagreements
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_d10_input() {
+        let generated = generate(10, 0, 600_000).unwrap();
+        let parsed = Source::Text(&generated.input)
+            .load_with(registry::get(10).unwrap())
+            .unwrap();
+        assert_eq!(parsed.solve(Part::One), Some(generated.part1.clone()));
+        let answers = crate::stream::solve(10, &Source::Text(&generated.input))
+            .unwrap()
+            .unwrap();
+        assert_eq!(answers, [Some(generated.part1), Some(generated.part2)]);
+    }
+
+    #[test]
+    fn test_seeded() {
+        assert_eq!(generate(8, 42, 50), generate(8, 42, 50));
+        assert_ne!(generate(8, 42, 50), generate(8, 43, 50));
+        assert_eq!(generate(3, 42, 50), None);
+    }
+}
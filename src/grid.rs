@@ -0,0 +1,228 @@
+use crate::parse::parse_lines;
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets to the 4 orthogonal neighbours, as `(dx, dy)`.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the 8 orthogonal and diagonal neighbours, as `(dx, dy)`.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of tiles stored row by row, addressed by `(x, y)` with
+/// `x` the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per non-blank line, mapping each character with `tile`.
+    /// `expected` describes the accepted characters for error reports.
+    pub fn parse<F>(input: &str, expected: &str, tile: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width = None;
+        let rows = parse_lines(input, |line| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(idx, c)| {
+                    tile(c).ok_or_else(|| ParseError::new(idx + 1, expected, c.to_string()))
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            let expected_width = *width.get_or_insert(row.len());
+            if row.len() != expected_width {
+                let column = expected_width.min(row.len()) + 1;
+                let expected = format!("a row of {} tiles", expected_width);
+                return Err(ParseError::new(column, expected, line));
+            }
+            Ok(row)
+        })?;
+        let height = rows.len();
+        Ok(Grid::new(
+            width.unwrap_or(0),
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Toroidal access: coordinates wrap around both edges.
+    pub fn get_wrapped(&self, x: usize, y: usize) -> &T {
+        &self[(x % self.width, y % self.height)]
+    }
+
+    /// The position `(dx, dy)` away from `(x, y)`, if it is inside the grid.
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    /// Positions of the orthogonal neighbours of `(x, y)` inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DIRECTIONS4)
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `(x, y)` inside
+    /// the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DIRECTIONS8)
+    }
+
+    /// Positions met walking from `(x, y)` by steps of `(dx, dy)`, excluding
+    /// the start, until leaving the grid.
+    pub fn cast(
+        &self,
+        x: usize,
+        y: usize,
+        dx: isize,
+        dy: isize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.offset(x, y, dx, dy), move |&(x, y)| {
+            self.offset(x, y, dx, dy)
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("position out of the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("position out of the grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(
+            digits("123\n45\n").unwrap_err(),
+            ParseError::new(3, "a row of 3 tiles", "45").at_line(2)
+        );
+        assert_eq!(
+            digits("1x3\n").unwrap_err(),
+            ParseError::new(2, "a digit", "x").at_line(1)
+        );
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_wrapped(4, 3), &5);
+        assert_eq!(grid.offset(0, 0, -1, 0), None);
+        assert_eq!(grid.offset(0, 0, 2, 1), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_neighbours_and_cast() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        let values = |it: &mut dyn Iterator<Item = (usize, usize)>| -> Vec<u32> {
+            it.map(|pos| grid[pos]).collect()
+        };
+        assert_eq!(values(&mut grid.neighbours4(0, 0)), vec![2, 4]);
+        assert_eq!(values(&mut grid.neighbours8(0, 0)), vec![2, 4, 5]);
+        assert_eq!(values(&mut grid.neighbours8(1, 1)).len(), 8);
+        assert_eq!(values(&mut grid.cast(0, 0, 1, 1)), vec![5, 9]);
+        assert_eq!(values(&mut grid.cast(2, 1, -1, 0)), vec![5, 4]);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::client::Verdict;
+use crate::parse::{column_of, parse_entries, parse_number};
+use crate::{ParseError, Part};
+use std::fmt;
+
+/// Default location of the record of submitted answers, relative to the
+/// repository root.
+pub static GUESSES_PATH: &str = "guesses.txt";
+
+/// How long the website makes wait after an answer when it does not say.
+pub const DEFAULT_WAIT: u64 = 60;
+
+/// An answer already submitted, with the verdict it got.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl fmt::Display for Guess {
+    /// Formats the guess as a line of the guesses file.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.day,
+            self.part,
+            self.verdict.keyword().unwrap_or("unknown"),
+            self.answer
+        )
+    }
+}
+
+/// An entry of the guesses file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    Guess(Guess),
+    /// The website refused an answer to `part` of `day` until the Unix time
+    /// `until`.
+    Wait {
+        day: u8,
+        part: Part,
+        until: u64,
+    },
+}
+
+impl fmt::Display for Record {
+    /// Formats the record as a line of the guesses file.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Record::Guess(guess) => guess.fmt(f),
+            Record::Wait { day, part, until } => write!(f, "{} {} wait {}", day, part, until),
+        }
+    }
+}
+
+impl Record {
+    /// Records that the website refuses answers for `seconds` from `now`, a
+    /// Unix time, or `DEFAULT_WAIT` seconds when it does not say.
+    pub fn wait(day: u8, part: Part, now: u64, seconds: Option<u64>) -> Self {
+        Record::Wait {
+            day,
+            part,
+            until: now + seconds.unwrap_or(DEFAULT_WAIT),
+        }
+    }
+}
+
+/// Parses a guesses file: one `<day> <part> <verdict> <answer>` entry per
+/// line, or `<day> <part> wait <unix time>` for a refused answer, lines
+/// starting with `#` being comments.
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    parse_entries(input, "'<day> <part> <verdict> <answer>'", |entry| {
+        let [keyword, answer] = entry.fields;
+        if keyword == "wait" {
+            return Ok(Record::Wait {
+                day: entry.day,
+                part: entry.part,
+                until: parse_number(answer, column_of(entry.line, answer))?,
+            });
+        }
+        let verdict = Verdict::from_keyword(keyword).ok_or_else(|| {
+            ParseError::new(
+                column_of(entry.line, keyword),
+                "right, wrong, too-high, too-low or wait",
+                keyword,
+            )
+        })?;
+        Ok(Record::Guess(Guess {
+            day: entry.day,
+            part: entry.part,
+            verdict,
+            answer: answer.to_string(),
+        }))
+    })
+}
+
+/// The Unix time until which the website refuses answers, if a recorded wait
+/// is still running at `now`. The limit is not specific to a part, so neither
+/// is the deadline.
+pub fn wait_until(records: &[Record], now: u64) -> Option<u64> {
+    records
+        .iter()
+        .filter_map(|record| match record {
+            Record::Wait { until, .. } => Some(*until),
+            Record::Guess(_) => None,
+        })
+        .filter(|&until| until > now)
+        .max()
+}
+
+/// Finds a previous guess making the submission of `answer` pointless: the
+/// same answer, a right answer to the part, or a numeric bound `answer` is
+/// already known to be beyond.
+pub fn previous<'a>(records: &'a [Record], day: u8, part: Part, answer: &str) -> Option<&'a Guess> {
+    let number = answer.parse::<i128>().ok();
+    records
+        .iter()
+        .filter_map(|record| match record {
+            Record::Guess(guess) => Some(guess),
+            Record::Wait { .. } => None,
+        })
+        .filter(|guess| guess.day == day && guess.part == part)
+        .find(|guess| {
+            let bound = guess.answer.parse::<i128>().ok();
+            guess.answer == answer
+                || match (&guess.verdict, number, bound) {
+                    (Verdict::Right, _, _) => true,
+                    (Verdict::TooHigh, Some(number), Some(bound)) => number >= bound,
+                    (Verdict::TooLow, Some(number), Some(bound)) => number <= bound,
+                    _ => false,
+                }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let guesses = parse("# comment\n1 2 too-high 500\n").unwrap();
+        assert_eq!(
+            guesses,
+            vec![Record::Guess(Guess {
+                day: 1,
+                part: Part::Two,
+                verdict: Verdict::TooHigh,
+                answer: "500".to_string(),
+            })]
+        );
+        assert_eq!(guesses[0].to_string(), "1 2 too-high 500");
+        let err = parse("1 2 maybe 500\n").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(5, "right, wrong, too-high, too-low or wait", "maybe").at_line(1)
+        );
+        assert!(parse("1 2 wait soon\n").is_err());
+    }
+
+    #[test]
+    fn test_wait() {
+        let wait = Record::wait(1, Part::One, 1000, Some(72));
+        assert_eq!(wait.to_string(), "1 1 wait 1072");
+        let guesses = parse(&format!("{}\n2 2 wait 1060\n", wait)).unwrap();
+        assert_eq!(guesses[0], wait);
+        assert_eq!(wait_until(&guesses, 1000), Some(1072));
+        assert_eq!(wait_until(&guesses, 1072), None);
+        assert_eq!(wait_until(&guesses[1..], 1000), Some(1060));
+        assert_eq!(previous(&guesses, 1, Part::One, "1072"), None);
+        assert_eq!(
+            Record::wait(1, Part::One, 1000, None),
+            Record::Wait {
+                day: 1,
+                part: Part::One,
+                until: 1060
+            }
+        );
+    }
+
+    #[test]
+    fn test_previous() {
+        let guesses = parse("1 1 wrong 12\n1 1 too-high 500\n1 1 too-low 100\n").unwrap();
+        let answer = |index: usize| match &guesses[index] {
+            Record::Guess(guess) => Some(guess),
+            Record::Wait { .. } => None,
+        };
+        assert_eq!(previous(&guesses, 1, Part::One, "12"), answer(0));
+        assert_eq!(previous(&guesses, 1, Part::One, "600"), answer(1));
+        assert_eq!(previous(&guesses, 1, Part::One, "100"), answer(2));
+        assert_eq!(previous(&guesses, 1, Part::One, "250"), None);
+        assert_eq!(previous(&guesses, 1, Part::Two, "12"), None);
+        assert_eq!(previous(&guesses, 2, Part::One, "12"), None);
+    }
+}
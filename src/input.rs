@@ -0,0 +1,116 @@
+use crate::registry::{Parsed, Runner};
+use crate::{ParseError, Solver};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source<'a> {
+    File(PathBuf),
+    Stdin,
+    Text(&'a str),
+}
+
+impl<'a> Source<'a> {
+    /// Interprets a command line argument: `-` is stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<Cow<'a, str>, InputError> {
+        let content = match self {
+            Source::File(path) => fs::read_to_string(path).map(Cow::Owned),
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map(|_| Cow::Owned(content))
+            }
+            Source::Text(text) => Ok(Cow::Borrowed(*text)),
+        };
+        content.map_err(|error| InputError::Io {
+            name: self.to_string(),
+            error,
+        })
+    }
+
+    /// Opens the input for reading piece by piece instead of whole.
+    pub fn reader(&self) -> Result<Box<dyn BufRead + 'a>, InputError> {
+        match self {
+            Source::File(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError::Io {
+                    name: self.to_string(),
+                    error,
+                }),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::Text(text) => Ok(Box::new(text.as_bytes())),
+        }
+    }
+
+    /// Reads and parses the input for `S`.
+    pub fn load<S: Solver>(&self) -> Result<S::Input, InputError> {
+        S::parse(&self.read()?).map_err(|err| self.locate(err))
+    }
+
+    /// Reads and parses the input with a type-erased solver.
+    pub fn load_with(&self, runner: &dyn Runner) -> Result<Box<dyn Parsed>, InputError> {
+        runner.parse(&self.read()?).map_err(|err| self.locate(err))
+    }
+
+    fn locate(&self, err: ParseError) -> InputError {
+        InputError::Parse(err.in_file(&self.to_string()))
+    }
+}
+
+impl fmt::Display for Source<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Text(_) => write!(f, "<string>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io { name: String, error: io::Error },
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { name, error } => write!(f, "could not load {}: {}", name, error),
+            InputError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::d01::Day01;
+
+    #[test]
+    fn test_load_text() {
+        let input = Source::Text("1721\n979\n366\n").load::<Day01>().unwrap();
+        assert_eq!(input, vec![366, 979, 1721]);
+        let err = Source::Text("12\nabc\n").load::<Day01>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<string>:2:1: expected a number, found \"abc\""
+        );
+    }
+}
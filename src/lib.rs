@@ -0,0 +1,149 @@
+use serde::Serialize;
+use std::fmt::{self, Display};
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod generate;
+pub mod grid;
+pub mod guesses;
+pub mod input;
+pub mod parse;
+pub mod registry;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod stream;
+
+pub use parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// The answer of one part, keeping its numeric type for machine-readable
+/// output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => value.fmt(f),
+            Answer::Unsigned(value) => value.fmt(f),
+            Answer::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed, i64, i32, i64);
+answer_from!(Unsigned, u64, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day-specific command for exploring a parsed input, such as
+/// `contains shiny gold` for day 7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Query {
+    pub name: &'static str,
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// A named alternative implementation of a part.
+pub type Variant<I, O> = (&'static str, fn(&I) -> Option<O>);
+
+/// A puzzle solution for a single day.
+///
+/// `parse` turns the raw puzzle input into the day's typed `Input`, or reports
+/// where the input is malformed. The parsed input is then shared by both
+/// parts. Parts return `None` when the input has no solution.
+///
+/// Days may also list `QUERIES` answered by `query`, which returns `None` for
+/// a name it does not know, and named alternative implementations of each
+/// part in `VARIANTS1` and `VARIANTS2`, expected to agree with `part1` and
+/// `part2`.
+pub trait Solver {
+    const DAY: u8;
+    const QUERIES: &'static [Query] = &[];
+    const VARIANTS1: &'static [Variant<Self::Input, Self::Output1>] = &[];
+    const VARIANTS2: &'static [Variant<Self::Input, Self::Output2>] = &[];
+    type Input: fmt::Debug + 'static;
+    type Output1: Into<Answer> + 'static;
+    type Output2: Into<Answer> + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Option<Self::Output1>;
+    fn part2(input: &Self::Input) -> Option<Self::Output2>;
+
+    fn query(_input: &Self::Input, _name: &str, _arg: &str) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+/// The message a panic was raised with, as given to `catch_unwind`.
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Conventional location of a day's puzzle input.
+pub fn input_path(day: u8) -> String {
+    format!("input/input{:02}.txt", day)
+}
@@ -0,0 +1,162 @@
+use crate::Part;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Describes why a puzzle input could not be parsed, and where.
+///
+/// Line and column numbers start at 1. Line parsers only know the column and
+/// leave the line to `parse_lines`; the file is attached by whoever read it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>, F: Into<String>>(column: usize, expected: E, found: F) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}", self.line, self.column)?,
+        }
+        write!(f, ": expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Non-blank lines of `input` with their line number, trailing whitespace
+/// removed.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .map(str::trim_end)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| (idx + 1, line))
+}
+
+/// Groups of consecutive non-blank lines, as used by blank-line-separated
+/// records.
+pub fn records(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut ret: Vec<Vec<(usize, &str)>> = Vec::new();
+    let mut last_line = 0;
+    for (line_no, line) in lines(input) {
+        if ret.is_empty() || line_no != last_line + 1 {
+            ret.push(Vec::new());
+        }
+        ret.last_mut().unwrap().push((line_no, line));
+        last_line = line_no;
+    }
+    ret
+}
+
+/// Parses every non-blank line with `f`, tagging errors with their line.
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines(input)
+        .map(|(line_no, line)| f(line).map_err(|err| err.at_line(line_no)))
+        .collect()
+}
+
+/// An entry of a `<day> <part> <field> <field>` file, as read by
+/// `parse_entries`.
+#[derive(Debug, Clone, Copy)]
+pub struct Entry<'a> {
+    pub line: &'a str,
+    pub day: u8,
+    pub part: Part,
+    /// The two fields after the part, which `column_of(line, ..)` locates.
+    pub fields: [&'a str; 2],
+}
+
+/// Parses a file of `<day> <part> <field> <field>` entries with `f`, one per
+/// line, lines starting with `#` being comments. `format` describes an entry
+/// to report malformed ones.
+pub fn parse_entries<T, F>(input: &str, format: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(Entry) -> Result<T, ParseError>,
+{
+    let entries = parse_lines(input, |line| {
+        if line.starts_with('#') {
+            return Ok(None);
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 4 {
+            return Err(ParseError::new(1, format, line));
+        }
+        let day = parse_number(fields[0], column_of(line, fields[0]))?;
+        let part_column = column_of(line, fields[1]);
+        let part = parse_number(fields[1], part_column)
+            .ok()
+            .and_then(Part::new)
+            .ok_or_else(|| ParseError::new(part_column, "part 1 or 2", fields[1]))?;
+        f(Entry {
+            line,
+            day,
+            part,
+            fields: [fields[2], fields[3]],
+        })
+        .map(Some)
+    })?;
+    Ok(entries.into_iter().flatten().collect())
+}
+
+/// 1-based column of `token`, which must be a subslice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    token.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+pub fn parse_number<T: FromStr>(token: &str, column: usize) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(column, "a number", token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines_reports_line_number() {
+        let err = parse_lines("1\n\n2\nx3\n", |line| parse_number::<u32>(line, 1)).unwrap_err();
+        assert_eq!(err, ParseError::new(1, "a number", "x3").at_line(4));
+        assert_eq!(
+            err.in_file("input.txt").to_string(),
+            "input.txt:4:1: expected a number, found \"x3\""
+        );
+    }
+
+    #[test]
+    fn test_records() {
+        let records = records("a\nb\n\n\nc\n");
+        assert_eq!(records, vec![vec![(1, "a"), (2, "b")], vec![(5, "c")]]);
+    }
+}
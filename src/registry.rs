@@ -0,0 +1,136 @@
+use crate::days::*;
+use crate::{Answer, ParseError, Part, Query, Solver, Variant};
+use std::marker::PhantomData;
+
+/// A day's solver with its input type erased, so that every day can be
+/// driven through the same interface.
+pub trait Runner: Sync {
+    fn day(&self) -> u8;
+    fn queries(&self) -> &'static [Query];
+    /// Names of the alternative implementations of `part`.
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+}
+
+/// A parsed puzzle input, ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Option<Answer>;
+    /// Pretty-prints the parsed structure.
+    fn dump(&self) -> String;
+    fn query(&self, name: &str, arg: &str) -> Option<Result<String, String>>;
+    /// Solves `part` with the variant `name`, `None` when there is no such
+    /// variant.
+    fn solve_variant(&self, part: Part, name: &str) -> Option<Option<Answer>>;
+}
+
+/// A variant whose answer differs from the main implementation of its part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement {
+    pub part: Part,
+    pub variant: &'static str,
+    pub expected: Option<Answer>,
+    pub found: Option<Answer>,
+}
+
+/// Solves every part with its main implementation and all of its variants,
+/// returning the variants that disagree.
+pub fn crosscheck(runner: &dyn Runner, parsed: &dyn Parsed) -> Vec<Disagreement> {
+    let mut ret = Vec::new();
+    for &part in &Part::ALL {
+        let expected = parsed.solve(part);
+        for variant in runner.variants(part) {
+            let found = parsed.solve_variant(part, variant).unwrap();
+            if found != expected {
+                ret.push(Disagreement {
+                    part,
+                    variant,
+                    expected: expected.clone(),
+                    found,
+                });
+            }
+        }
+    }
+    ret
+}
+
+struct Entry<S>(PhantomData<fn() -> S>);
+
+struct Loaded<S: Solver>(S::Input);
+
+impl<S: Solver + 'static> Runner for Entry<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn queries(&self) -> &'static [Query] {
+        S::QUERIES
+    }
+
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        match part {
+            Part::One => S::VARIANTS1.iter().map(|&(name, _)| name).collect(),
+            Part::Two => S::VARIANTS2.iter().map(|&(name, _)| name).collect(),
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        Ok(Box::new(Loaded::<S>(S::parse(input)?)))
+    }
+}
+
+impl<S: Solver> Parsed for Loaded<S> {
+    fn solve(&self, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => S::part1(&self.0).map(Into::into),
+            Part::Two => S::part2(&self.0).map(Into::into),
+        }
+    }
+
+    fn dump(&self) -> String {
+        format!("{:#?}", self.0)
+    }
+
+    fn query(&self, name: &str, arg: &str) -> Option<Result<String, String>> {
+        S::query(&self.0, name, arg)
+    }
+
+    fn solve_variant(&self, part: Part, name: &str) -> Option<Option<Answer>> {
+        fn find<I, O: Into<Answer>>(
+            variants: &[Variant<I, O>],
+            input: &I,
+            name: &str,
+        ) -> Option<Option<Answer>> {
+            let &(_, variant) = variants.iter().find(|&&(variant, _)| variant == name)?;
+            Some(variant(input).map(Into::into))
+        }
+        match part {
+            Part::One => find(S::VARIANTS1, &self.0, name),
+            Part::Two => find(S::VARIANTS2, &self.0, name),
+        }
+    }
+}
+
+static SOLVERS: &[&dyn Runner] = &[
+    &Entry::<d01::Day01>(PhantomData),
+    &Entry::<d02::Day02>(PhantomData),
+    &Entry::<d03::Day03>(PhantomData),
+    &Entry::<d04::Day04>(PhantomData),
+    &Entry::<d05::Day05>(PhantomData),
+    &Entry::<d06::Day06>(PhantomData),
+    &Entry::<d07::Day07>(PhantomData),
+    &Entry::<d08::Day08>(PhantomData),
+    &Entry::<d09::Day09>(PhantomData),
+    &Entry::<d10::Day10>(PhantomData),
+    &Entry::<d11::Day11>(PhantomData),
+    &Entry::<d12::Day12>(PhantomData),
+    &Entry::<d13::Day13>(PhantomData),
+];
+
+/// Every registered solver, ordered by day.
+pub fn all() -> &'static [&'static dyn Runner] {
+    SOLVERS
+}
+
+pub fn get(day: u8) -> Option<&'static dyn Runner> {
+    SOLVERS.iter().copied().find(|runner| runner.day() == day)
+}
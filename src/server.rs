@@ -0,0 +1,378 @@
+use crate::registry;
+use crate::{panic_message, Answer, Part};
+use log::{error, info};
+use serde::Serialize;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// How long `aoc serve` lets a request run by default.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The longest input `aoc serve` reads by default, in bytes.
+pub const DEFAULT_MAX_BODY: u64 = 1 << 20;
+
+/// How many requests `aoc serve` handles at once by default.
+pub const DEFAULT_WORKERS: usize = 4;
+
+/// What a server gives each request, and how many it handles at once.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    pub timeout: Duration,
+    pub max_body: u64,
+    pub workers: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            timeout: DEFAULT_TIMEOUT,
+            max_body: DEFAULT_MAX_BODY,
+            workers: DEFAULT_WORKERS,
+        }
+    }
+}
+
+type Handler = fn(&Method, &str, &str) -> Reply;
+
+/// The answer to `POST /days/{day}/parts/{part}`, durations being in seconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct Solved {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub parse_duration: f64,
+    pub solve_duration: f64,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+/// A response before it is written: its HTTP status and JSON body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Reply {
+    fn ok<T: Serialize>(value: &T) -> Self {
+        Reply {
+            status: 200,
+            body: serde_json::to_string(value).unwrap(),
+        }
+    }
+
+    fn error(status: u16, error: String) -> Self {
+        Reply {
+            status,
+            body: serde_json::to_string(&Failure { error }).unwrap(),
+        }
+    }
+}
+
+/// Routes a request to the registered solvers:
+///
+/// * `GET /days` lists the days with a solver.
+/// * `POST /days/{day}/parts/{part}` solves the part for the input in `body`.
+pub fn handle(method: &Method, url: &str, body: &str) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => Reply::ok(
+            &registry::all()
+                .iter()
+                .map(|runner| runner.day())
+                .collect::<Vec<_>>(),
+        ),
+        (Method::Post, ["days", day, "parts", part]) => solve(day, part, body),
+        (_, ["days"]) | (_, ["days", _, "parts", _]) => {
+            Reply::error(405, format!("{} is not allowed on {}", method, path))
+        }
+        _ => Reply::error(404, format!("No route for {}", path)),
+    }
+}
+
+fn solve(day: &str, part: &str, body: &str) -> Reply {
+    let runner = match day.parse().ok().and_then(registry::get) {
+        Some(runner) => runner,
+        None => return Reply::error(404, format!("No solver registered for day {}", day)),
+    };
+    let part = match part.parse().ok().and_then(Part::new) {
+        Some(part) => part,
+        None => return Reply::error(404, format!("Unknown part {}", part)),
+    };
+    let start = Instant::now();
+    let parsed = match runner.parse(body) {
+        Ok(parsed) => parsed,
+        Err(err) => return Reply::error(400, err.to_string()),
+    };
+    let parse_duration = start.elapsed();
+    let start = Instant::now();
+    let answer = parsed.solve(part);
+    Reply::ok(&Solved {
+        day: runner.day(),
+        part: part.number(),
+        answer,
+        parse_duration: parse_duration.as_secs_f64(),
+        solve_duration: start.elapsed().as_secs_f64(),
+    })
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Frees a slot of its pool when dropped. A job drops it before answering, so
+/// that whoever gets the answer finds the slot free again.
+struct Release(Arc<AtomicUsize>);
+
+impl Drop for Release {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A fixed number of threads running jobs, refusing new ones while every
+/// thread is busy instead of queueing them.
+struct Pool {
+    jobs: Mutex<mpsc::Sender<Job>>,
+    busy: Arc<AtomicUsize>,
+    size: usize,
+}
+
+impl Pool {
+    fn new(size: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..size {
+            let receiver = Arc::clone(&receiver);
+            thread::spawn(move || loop {
+                let job = match receiver.lock().unwrap().recv() {
+                    Ok(job) => job,
+                    Err(_) => break,
+                };
+                // A panicking job releases its slot while unwinding.
+                let _ = panic::catch_unwind(AssertUnwindSafe(job));
+            });
+        }
+        Pool {
+            jobs: Mutex::new(sender),
+            busy: Arc::new(AtomicUsize::new(0)),
+            size,
+        }
+    }
+
+    /// Reserves a thread for a job, `None` when every thread is busy.
+    fn reserve(&self) -> Option<Release> {
+        let size = self.size;
+        self.busy
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |busy| {
+                Some(busy + 1).filter(|&busy| busy <= size)
+            })
+            .ok()?;
+        Some(Release(Arc::clone(&self.busy)))
+    }
+
+    /// Runs `job` on the thread `release` reserved.
+    fn execute<F>(&self, release: Release, job: F)
+    where
+        F: FnOnce(Release) + Send + 'static,
+    {
+        let job: Job = Box::new(move || job(release));
+        self.jobs.lock().unwrap().send(job).unwrap();
+    }
+}
+
+/// Runs `handler` on a thread of `solvers`, answering 500 if it panics, 504
+/// if it takes longer than `timeout` and 503 if every solver is busy. A timed
+/// out handler cannot be stopped: it keeps its thread until it returns.
+fn handle_isolated(
+    handler: Handler,
+    solvers: &Pool,
+    method: Method,
+    url: String,
+    body: String,
+    timeout: Duration,
+) -> Reply {
+    let (sender, receiver) = mpsc::channel();
+    let job = move |release: Release| {
+        let reply = panic::catch_unwind(AssertUnwindSafe(|| handler(&method, &url, &body)))
+            .unwrap_or_else(|payload| {
+                Reply::error(
+                    500,
+                    format!("The solver panicked: {}", panic_message(payload.as_ref())),
+                )
+            });
+        drop(release);
+        // The receiver is gone if the request timed out.
+        let _ = sender.send(reply);
+    };
+    match solvers.reserve() {
+        Some(release) => solvers.execute(release, job),
+        None => return Reply::error(503, "Every solver is busy, retry later".to_string()),
+    }
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or_else(|_| Reply::error(504, format!("The solver took longer than {:?}", timeout)))
+}
+
+/// Reads at most `limit` bytes of the body of `request`, `None` if it is
+/// longer.
+fn read_body(request: &mut Request, limit: u64) -> Result<Option<String>, io::Error> {
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(limit + 1)
+        .read_to_string(&mut body)?;
+    Ok(Some(body).filter(|body| body.len() as u64 <= limit))
+}
+
+fn send(request: Request, reply: Reply) {
+    info!("{} {}: {}", request.method(), request.url(), reply.status);
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(response) {
+        error!("Could not send the response: {}", err);
+    }
+}
+
+fn respond(
+    mut request: Request,
+    handler: Handler,
+    solvers: &Pool,
+    limits: &Limits,
+    release: Release,
+) {
+    let reply = match read_body(&mut request, limits.max_body) {
+        Ok(Some(body)) => handle_isolated(
+            handler,
+            solvers,
+            request.method().clone(),
+            request.url().to_string(),
+            body,
+            limits.timeout,
+        ),
+        Ok(None) => Reply::error(
+            413,
+            format!("The input is longer than {} bytes", limits.max_body),
+        ),
+        Err(err) => Reply::error(400, format!("Could not read the input: {}", err)),
+    };
+    drop(release);
+    send(request, reply);
+}
+
+/// Answers the requests of `server` with `handler`. A pool of threads reads
+/// the requests and another runs the handler, each of `limits.workers`
+/// threads: a request finding either saturated gets a 503.
+fn run(server: Server, handler: Handler, limits: Limits) {
+    let limits = Arc::new(limits);
+    let responders = Pool::new(limits.workers);
+    let solvers = Arc::new(Pool::new(limits.workers));
+    for request in server.incoming_requests() {
+        let release = match responders.reserve() {
+            Some(release) => release,
+            None => {
+                send(
+                    request,
+                    Reply::error(503, "The server is busy, retry later".to_string()),
+                );
+                continue;
+            }
+        };
+        let (solvers, limits) = (Arc::clone(&solvers), Arc::clone(&limits));
+        responders.execute(release, move |release| {
+            respond(request, handler, &solvers, &limits, release)
+        });
+    }
+}
+
+/// Serves the solvers on `addr` until the process is stopped, within
+/// `limits`.
+pub fn serve(addr: &str, limits: Limits) -> Result<(), String> {
+    let server =
+        Server::http(addr).map_err(|err| format!("Could not listen on {}: {}", addr, err))?;
+    run(server, handle, limits);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let reply = handle(
+            &Method::Post,
+            "/days/1/parts/2",
+            include_str!("../examples/example01.txt"),
+        );
+        assert_eq!(reply.status, 200);
+        let body: serde_json::Value = serde_json::from_str(&reply.body).unwrap();
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 2);
+        assert_eq!(body["answer"], 241861950);
+        assert!(body["solve_duration"].is_f64());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            handle(&Method::Post, "/days/1/parts/2", "12\nab\n").status,
+            400
+        );
+        assert_eq!(handle(&Method::Post, "/days/30/parts/1", "").status, 404);
+        assert_eq!(handle(&Method::Post, "/days/1/parts/3", "").status, 404);
+        assert_eq!(handle(&Method::Get, "/days/1/parts/1", "").status, 405);
+        assert_eq!(handle(&Method::Get, "/nowhere", "").status, 404);
+        assert_eq!(
+            handle(&Method::Get, "/days", "").body,
+            "[1,2,3,4,5,6,7,8,9,10,11,12,13]"
+        );
+    }
+
+    #[test]
+    fn test_isolation() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handler: Handler = |method, url, body| match url {
+            "/panic" => panic!("bad input"),
+            "/spin" => loop {
+                thread::sleep(Duration::from_millis(50));
+            },
+            _ => handle(method, url, body),
+        };
+        let limits = Limits {
+            timeout: Duration::from_millis(200),
+            max_body: 16,
+            workers: 2,
+        };
+        thread::spawn(move || run(server, handler, limits));
+
+        let status = |result: Result<ureq::Response, ureq::Error>| match result {
+            Ok(response) => response.status(),
+            Err(ureq::Error::Status(status, _)) => status,
+            Err(err) => panic!("{}", err),
+        };
+        let get = |path: &str| status(ureq::get(&format!("{}{}", url, path)).call());
+        let post = |path: &str, body: &str| {
+            status(ureq::post(&format!("{}{}", url, path)).send_string(body))
+        };
+        assert_eq!(get("/panic"), 500);
+        assert_eq!(get("/days"), 200);
+        assert_eq!(get("/spin"), 504);
+        assert_eq!(get("/days"), 200);
+        assert_eq!(post("/days/1/parts/1", "1010\n1010\n"), 200);
+        assert_eq!(post("/days/1/parts/1", &"1010\n".repeat(4)), 413);
+        // Both solvers now spin forever.
+        assert_eq!(get("/spin"), 504);
+        assert_eq!(get("/days"), 503);
+    }
+}
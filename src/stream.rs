@@ -0,0 +1,238 @@
+use crate::days::{d01, d04, d06, d09, d10};
+use crate::input::{InputError, Source};
+use crate::parse::{parse_number, ParseError};
+use crate::Answer;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Non-blank lines read from a `BufRead` with their line number, trailing
+/// whitespace removed. The streaming counterpart of `parse::lines`: only one
+/// line is held in memory at a time.
+pub struct Lines<R> {
+    reader: R,
+    line_no: usize,
+}
+
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines { reader, line_no: 0 }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<(usize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => return Some(Err(err)),
+            }
+            self.line_no += 1;
+            let len = line.trim_end().len();
+            if len > 0 {
+                line.truncate(len);
+                return Some(Ok((self.line_no, line)));
+            }
+        }
+    }
+}
+
+/// Groups of consecutive non-blank lines, the streaming counterpart of
+/// `parse::records`: only one record is held in memory at a time.
+pub struct Records<I> {
+    lines: I,
+    pending: Option<(usize, String)>,
+}
+
+pub fn records<I>(lines: I) -> Records<I>
+where
+    I: Iterator<Item = io::Result<(usize, String)>>,
+{
+    Records {
+        lines,
+        pending: None,
+    }
+}
+
+impl<I> Iterator for Records<I>
+where
+    I: Iterator<Item = io::Result<(usize, String)>>,
+{
+    type Item = io::Result<Vec<(usize, String)>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = match self.pending.take() {
+            Some(line) => vec![line],
+            None => match self.lines.next()? {
+                Ok(line) => vec![line],
+                Err(err) => return Some(Err(err)),
+            },
+        };
+        for line in &mut self.lines {
+            match line {
+                Ok(line) if line.0 == record.last().unwrap().0 + 1 => record.push(line),
+                Ok(line) => {
+                    self.pending = Some(line);
+                    break;
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        Some(Ok(record))
+    }
+}
+
+impl Source<'_> {
+    /// Parses every non-blank line of the input with `f` as it is read,
+    /// locating errors like `load` does.
+    pub fn parse_lines<T, F>(
+        &self,
+        mut f: F,
+    ) -> Result<impl Iterator<Item = Result<T, InputError>> + '_, InputError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError> + 'static,
+    {
+        Ok(lines(self.reader()?).map(move |line| {
+            let (line_no, line) = line.map_err(|error| InputError::Io {
+                name: self.to_string(),
+                error,
+            })?;
+            f(&line)
+                .map_err(|err| InputError::Parse(err.at_line(line_no).in_file(&self.to_string())))
+        }))
+    }
+
+    /// Parses every record of the input, a group of consecutive non-blank
+    /// lines, with `f` as it is read. `f` gets the line numbers and locates
+    /// its errors itself.
+    pub fn parse_records<T, F>(
+        &self,
+        mut f: F,
+    ) -> Result<impl Iterator<Item = Result<T, InputError>> + '_, InputError>
+    where
+        F: FnMut(&[(usize, &str)]) -> Result<T, ParseError> + 'static,
+    {
+        Ok(records(lines(self.reader()?)).map(move |record| {
+            let record = record.map_err(|error| InputError::Io {
+                name: self.to_string(),
+                error,
+            })?;
+            let record: Vec<(usize, &str)> = record
+                .iter()
+                .map(|(line_no, line)| (*line_no, line.as_str()))
+                .collect();
+            f(&record).map_err(|err| InputError::Parse(err.in_file(&self.to_string())))
+        }))
+    }
+}
+
+fn numbers<'a, T: FromStr + 'static>(
+    source: &'a Source,
+) -> Result<impl Iterator<Item = Result<T, InputError>> + 'a, InputError> {
+    source.parse_lines(|line| parse_number(line, 1))
+}
+
+/// The days able to solve an input as it is read, in bounded memory.
+pub static DAYS: &[u8] = &[1, 4, 6, 9, 10];
+
+/// Solves both parts of `day` while streaming its input, or returns `None`
+/// when the day cannot be streamed.
+pub fn solve(day: u8, source: &Source) -> Option<Result<[Option<Answer>; 2], InputError>> {
+    let answers = match day {
+        1 => source
+            .parse_lines(d01::parse_entry)
+            .and_then(d01::stream)
+            .map(|(one, two)| [one.map(Answer::from), two.map(Answer::from)]),
+        4 => source
+            .parse_records(d04::Passport::new)
+            .and_then(d04::stream)
+            .map(|(one, two)| [Some(Answer::from(one)), Some(Answer::from(two))]),
+        6 => source
+            .parse_records(d06::parse_group)
+            .and_then(d06::stream)
+            .map(|(one, two)| [Some(Answer::from(one)), Some(Answer::from(two))]),
+        9 => solve_day09(source),
+        10 => source
+            .parse_lines(d10::parse_joltage)
+            .and_then(d10::stream)
+            .map(|(one, two)| [Some(Answer::from(one)), two.map(Answer::from)]),
+        _ => return None,
+    };
+    Some(answers)
+}
+
+// Part 2 needs the answer of part 1, so the input is read twice.
+fn solve_day09(source: &Source) -> Result<[Option<Answer>; 2], InputError> {
+    let one = d09::stream_part1(numbers(source)?, d09::PREAMBLE_LEN)?;
+    let two = match one {
+        Some(_) if *source == Source::Stdin => {
+            return Err(InputError::Io {
+                name: source.to_string(),
+                error: io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "day 9 reads its input twice, which stdin cannot be",
+                ),
+            })
+        }
+        Some(target) => d09::stream_part2(numbers(source)?, target)?,
+        None => None,
+    };
+    Ok([one.map(Answer::from), two.map(Answer::from)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let source = Source::Text(include_str!("../examples/example10-2.txt"));
+        let answers = solve(10, &source).unwrap().unwrap();
+        assert_eq!(
+            answers,
+            [Some(Answer::Unsigned(220)), Some(Answer::Unsigned(19208))]
+        );
+        let err = solve(1, &Source::Text("1721\n97x\n")).unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<string>:2:1: expected a number, found \"97x\""
+        );
+        let source = Source::Text(include_str!("../examples/example06.txt"));
+        assert_eq!(
+            solve(6, &source).unwrap().unwrap(),
+            [Some(Answer::Unsigned(11)), Some(Answer::Unsigned(6))]
+        );
+        let err = solve(4, &Source::Text("byr:1937\n\niyr:2017 xyz:1\n"))
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().starts_with("<string>:3:10: "), "{}", err);
+        assert!(solve(7, &source).is_none());
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<(usize, String)> = lines("a \n\n b\n".as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(lines, vec![(1, "a".to_string()), (3, " b".to_string())]);
+    }
+
+    #[test]
+    fn test_records_match_parse() {
+        let input = include_str!("../examples/example04.txt");
+        let streamed: Vec<Vec<(usize, String)>> = records(lines(input.as_bytes()))
+            .collect::<io::Result<_>>()
+            .unwrap();
+        let parsed: Vec<Vec<(usize, String)>> = crate::parse::records(input)
+            .into_iter()
+            .map(|record| {
+                record
+                    .into_iter()
+                    .map(|(line_no, line)| (line_no, line.to_string()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(streamed, parsed);
+    }
+}